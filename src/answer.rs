@@ -0,0 +1,172 @@
+use num_bigint::BigUint;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The answer of a puzzle part, whatever the type the solution computes it in.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    BigUint(BigUint),
+    Str(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::I64(value) => write!(f, "{}", value),
+            Answer::U64(value) => write!(f, "{}", value),
+            Answer::I128(value) => write!(f, "{}", value),
+            Answer::U128(value) => write!(f, "{}", value),
+            Answer::BigUint(value) => write!(f, "{}", value),
+            Answer::Str(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Answers are equal when they are written the same way: `I64(5)` equals `U64(5)`.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Str(left), Answer::Str(right)) => left == right,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+/// Reads an answer back, as the narrowest numeric variant it fits in.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse::<i64>() {
+            Ok(Answer::I64(value))
+        } else if let Ok(value) = s.parse::<u64>() {
+            Ok(Answer::U64(value))
+        } else if let Ok(value) = s.parse::<i128>() {
+            Ok(Answer::I128(value))
+        } else if let Ok(value) = s.parse::<u128>() {
+            Ok(Answer::U128(value))
+        } else if let Ok(value) = s.parse::<BigUint>() {
+            Ok(Answer::BigUint(value))
+        } else {
+            Ok(Answer::Str(s.to_string()))
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::I64(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::I64(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::U64(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::U64(value as u64)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::I128(value)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::U128(value)
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        Answer::BigUint(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(
+            Answer::from(171741365473332u64).to_string(),
+            "171741365473332"
+        );
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+
+        let big = BigUint::from(u128::MAX) * 10u32;
+        assert_eq!(
+            Answer::from(big).to_string(),
+            "3402823669209384634633746074317682114550"
+        );
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Answer::I64(1031), Answer::U64(1031));
+        assert_eq!(Answer::U128(1031), Answer::BigUint(BigUint::from(1031u32)));
+        assert_eq!(Answer::from(1031usize), Answer::from(1031));
+        assert_ne!(Answer::I64(-1), Answer::U64(1));
+        assert_ne!(Answer::from("abc"), Answer::from("abd"));
+    }
+
+    #[test]
+    fn from_str() {
+        assert!(matches!("-12".parse(), Ok(Answer::I64(-12))));
+        assert!(matches!(
+            "18446744073709551615".parse(),
+            Ok(Answer::U64(u64::MAX))
+        ));
+        assert!(matches!(
+            "-18446744073709551616".parse(),
+            Ok(Answer::I128(_))
+        ));
+        assert!(matches!(
+            "340282366920938463463374607431768211455".parse(),
+            Ok(Answer::U128(_))
+        ));
+        assert!(matches!(
+            "340282366920938463463374607431768211456".parse(),
+            Ok(Answer::BigUint(_))
+        ));
+        assert_eq!(
+            "ABC".parse::<Answer>().unwrap(),
+            Answer::Str(String::from("ABC"))
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::layout::ProfileArgs;
+use crate::memory::{self, Usage, format_bytes};
+use crate::runner::{check_input, parse, read_input};
+use crate::solution::registry;
+use clap::Args;
+use std::time::{Duration, Instant};
+
+#[derive(Args, Debug)]
+pub(crate) struct BenchArgs {
+    /// Year of the puzzles
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Only benchmark this day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Number of repetitions of each step
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// The durations of the repetitions of a step.
+#[derive(Debug, Clone)]
+pub(crate) struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    pub(crate) fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Timings { samples }
+    }
+
+    pub(crate) fn min(&self) -> Duration {
+        self.samples.first().copied().unwrap_or_default()
+    }
+
+    pub(crate) fn median(&self) -> Duration {
+        self.samples
+            .get(self.samples.len() / 2)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn max(&self) -> Duration {
+        self.samples.last().copied().unwrap_or_default()
+    }
+}
+
+/// Runs `f` `runs` times, returning the timings and the result of the last run.
+pub(crate) fn measure<T>(runs: u32, mut f: impl FnMut() -> T) -> (Timings, T) {
+    let mut samples = Vec::new();
+    let mut result = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        result = Some(f());
+        samples.push(start.elapsed());
+    }
+    (Timings::new(samples), result.unwrap())
+}
+
+/// Formats a duration with a unit suited to its magnitude.
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+/// The memory columns of a step in the table.
+fn format_usage(usage: Usage) -> String {
+    format!(
+        " {} | {} | {} |",
+        format_bytes(usage.peak),
+        format_bytes(usage.allocated),
+        usage.allocations
+    )
+}
+
+/// Times the parsing and each part of the registered days, and prints a
+/// markdown table of the timings, along with the memory of a single run
+/// when built with the counting allocator.
+pub(crate) fn bench(args: &BenchArgs) -> Result<(), String> {
+    let layout = args.profile.layout();
+    let mut rows = Vec::new();
+    for ((year, day), puzzle) in registry() {
+        if year != args.year || args.day.is_some_and(|d| d != day) {
+            continue;
+        }
+
+        let path = layout.input(year, day);
+        let input = check_input(&puzzle, &read_input(&path)?, &path);
+        let (timings, solution) = measure(args.runs, || parse(puzzle.parse, &input, &path));
+        let solution = solution?;
+        let (_, usage) = memory::measure(|| parse(puzzle.parse, &input, &path));
+        rows.push((year, day, String::from("parse"), timings, usage));
+
+        for part in [1, 2] {
+            let (timings, _) = measure(args.runs, || solution.solve(part));
+            let (_, usage) = memory::measure(|| solution.solve(part));
+            rows.push((year, day, format!("part {}", part), timings, usage));
+        }
+    }
+
+    let memory = memory::enabled();
+    if memory {
+        println!("| Year | Day | Step | Min | Median | Max | Peak | Allocated | Allocations |");
+        println!("|------|-----|------|----:|-------:|----:|-----:|----------:|------------:|");
+    } else {
+        println!("| Year | Day | Step | Min | Median | Max |");
+        println!("|------|-----|------|----:|-------:|----:|");
+    }
+    for (year, day, step, timings, usage) in rows {
+        println!(
+            "| {} | {:02} | {} | {} | {} | {} |{}",
+            year,
+            day,
+            step,
+            format_duration(timings.min()),
+            format_duration(timings.median()),
+            format_duration(timings.max()),
+            if memory {
+                format_usage(usage)
+            } else {
+                String::new()
+            }
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{Timings, format_duration, measure};
+    use std::time::Duration;
+
+    #[test]
+    fn timings() {
+        let timings = Timings::new(vec![
+            Duration::from_millis(5),
+            Duration::from_millis(1),
+            Duration::from_millis(3),
+        ]);
+
+        assert_eq!(timings.min(), Duration::from_millis(1));
+        assert_eq!(timings.median(), Duration::from_millis(3));
+        assert_eq!(timings.max(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn measure_runs() {
+        let mut count = 0;
+        let (timings, result) = measure(4, || {
+            count += 1;
+            count
+        });
+
+        assert_eq!(result, 4);
+        assert!(timings.min() <= timings.max());
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(12)), "12 ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3 µs");
+        assert_eq!(format_duration(Duration::from_micros(12_345)), "12.3 ms");
+        assert_eq!(format_duration(Duration::from_millis(12_345)), "12.35 s");
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    bench, client, debugger, differential, executor, generator, importer, leaderboard, progress,
+    runner, sanity, submit, vault, verify, watch,
+};
+use clap::{Parser, Subcommand};
+
+/// Advent of Code solutions runner.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run a day, or every day of a year, against its input
+    Run(runner::RunArgs),
+    /// Run every day of a year in parallel and summarize the results
+    RunAll(executor::RunAllArgs),
+    /// Check every day of a year against the answers manifest
+    Verify(verify::VerifyArgs),
+    /// Download the input of a day, unless it is already there
+    Download(client::DownloadArgs),
+    /// Submit the answer of a part, unless it is known to be wrong
+    Submit(submit::SubmitArgs),
+    /// Create the source of a day from its saved puzzle page
+    Import(importer::ImportArgs),
+    /// Step through the simulation of a day, printing its grid
+    Debug(debugger::DebugArgs),
+    /// Compare the days having a brute-force reference with it on random inputs
+    Oracle(differential::OracleArgs),
+    /// Write random inputs of a year to a profile, from a seed
+    Generate(generator::GenerateArgs),
+    /// Check the whitespace of the inputs of a year
+    Check(sanity::CheckArgs),
+    /// Time the parsing and each part of every day of a year
+    Bench(bench::BenchArgs),
+    /// Analyze the JSON export of a private leaderboard
+    Leaderboard(leaderboard::LeaderboardArgs),
+    /// Show the star calendar of each year, with the best runtimes
+    Progress(progress::ProgressArgs),
+    /// Keep the inputs encrypted in the repository
+    Vault(vault::VaultArgs),
+    /// Run a day again whenever its input or source changes
+    Watch(watch::WatchArgs),
+}
+
+impl Cli {
+    /// Runs the command, returning the message to print when it fails.
+    pub fn run(&self) -> Result<(), String> {
+        match &self.command {
+            Command::Run(args) => runner::run(args),
+            Command::RunAll(args) => executor::run_all(args),
+            Command::Verify(args) => verify::verify(args),
+            Command::Debug(args) => debugger::debug(args),
+            Command::Oracle(args) => differential::oracle(args),
+            Command::Generate(args) => generator::generate(args),
+            Command::Check(args) => sanity::check_all(args),
+            Command::Bench(args) => bench::bench(args),
+            Command::Download(args) => client::download(args),
+            Command::Submit(args) => submit::submit(args),
+            Command::Import(args) => importer::import(args),
+            Command::Leaderboard(args) => leaderboard::leaderboard(args),
+            Command::Progress(args) => progress::progress(args),
+            Command::Vault(args) => vault::vault(args),
+            Command::Watch(args) => watch::watch(args),
+        }
+    }
+}
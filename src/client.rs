@@ -0,0 +1,247 @@
+use crate::layout::ProfileArgs;
+use crate::vault;
+use clap::Args;
+use std::path::{Path, PathBuf};
+use ureq::Agent;
+
+/// Where the puzzles are, unless a stand-in server is given.
+pub(crate) const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the puzzle site, as its author asks automated tools to do.
+pub(crate) const USER_AGENT: &str = concat!(
+    "github.com/ZongoForSpeed/RustAOC/",
+    env!("CARGO_PKG_VERSION"),
+    " (ureq)"
+);
+
+/// Options shared by the commands talking to the puzzle site.
+#[derive(Args, Debug)]
+pub(crate) struct ClientArgs {
+    /// File holding the value of the `session` cookie of the puzzle site
+    #[arg(long, default_value = ".session")]
+    session: PathBuf,
+
+    /// Base URL of the puzzle site
+    #[arg(long, default_value = BASE_URL)]
+    base_url: String,
+}
+
+/// An HTTP client for the puzzle site, authenticated by a session cookie.
+pub(crate) struct Client {
+    base_url: String,
+    session: String,
+    agent: Agent,
+}
+
+impl Client {
+    pub(crate) fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .new_agent();
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent,
+        }
+    }
+
+    /// Builds a client from the command-line options, reading the session cookie.
+    pub(crate) fn from_args(args: &ClientArgs) -> Result<Self, String> {
+        let session = std::fs::read_to_string(&args.session).map_err(|e| {
+            format!(
+                "cannot read the session cookie from {}: {}",
+                args.session.display(),
+                e
+            )
+        })?;
+        let session = session.trim();
+        if session.is_empty() {
+            return Err(format!("{} is empty", args.session.display()));
+        }
+        Ok(Client::new(&args.base_url, session))
+    }
+
+    pub(crate) fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, page)
+    }
+
+    pub(crate) fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub(crate) fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day, "/input");
+        self.agent
+            .get(&url)
+            .header("Cookie", self.cookie())
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| format!("cannot download {}: {}", url, e))
+    }
+
+    /// Posts the answer of a part, returning the page telling whether it is right.
+    pub(crate) fn answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, String> {
+        let url = self.url(year, day, "/answer");
+        let level = part.to_string();
+        self.agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| format!("cannot submit to {}: {}", url, e))
+    }
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct DownloadArgs {
+    /// Year of the puzzle
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// Downloads the input of a day to `path`, unless it is already there.
+/// Returns whether the input was downloaded.
+pub(crate) fn download_input(
+    client: &Client,
+    year: u16,
+    day: u8,
+    path: &Path,
+) -> Result<bool, String> {
+    if vault::exists(path) {
+        return Ok(false);
+    }
+
+    let input = client.input(year, day)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("cannot create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, input).map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+    Ok(true)
+}
+
+pub(crate) fn download(args: &DownloadArgs) -> Result<(), String> {
+    let path = args.profile.layout().input(args.year, args.day);
+    if vault::exists(&path) {
+        println!("{} already downloaded", path.display());
+        return Ok(());
+    }
+
+    let client = Client::from_args(&args.client)?;
+    match download_input(&client, args.year, args.day, &path)? {
+        true => println!("{} downloaded", path.display()),
+        false => println!("{} already downloaded", path.display()),
+    }
+    Ok(())
+}
+
+/// A local stand-in for the puzzle site, so the tests never hit the real one.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Starts a server answering each request, in turn, with the given status
+    /// and body. Returns its base URL and a handle on the received requests,
+    /// headers and body included.
+    pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8_lossy(&content));
+                requests.push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{Client, USER_AGENT, download_input, stand_in};
+
+    #[test]
+    fn download_once() {
+        let (url, server) = stand_in::serve(vec![(200, "L68\nL30\n")]);
+        let client = Client::new(&url, "53c2e7");
+        let dir = std::env::temp_dir().join(format!("rustaoc-download-{}", std::process::id()));
+        let path = dir.join("day01.txt");
+
+        assert_eq!(download_input(&client, 2025, 1, &path), Ok(true));
+        // The file is cached: the server, which only answers once, is not asked again.
+        assert_eq!(download_input(&client, 2025, 1, &path), Ok(false));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "L68\nL30\n");
+
+        let requests = server.join().unwrap();
+        let request = requests[0].to_lowercase();
+        assert!(request.starts_with("get /2025/day/1/input http/1.1"));
+        assert!(request.contains("cookie: session=53c2e7"));
+        assert!(request.contains(&format!("user-agent: {}", USER_AGENT.to_lowercase())));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn download_error() {
+        let (url, server) = stand_in::serve(vec![(400, "Please log in")]);
+        let client = Client::new(&url, "expired");
+        let dir = std::env::temp_dir().join(format!("rustaoc-error-{}", std::process::id()));
+        let path = dir.join("day02.txt");
+
+        assert!(download_input(&client, 2025, 2, &path).is_err());
+        assert!(!path.exists());
+
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+use crate::layout::ProfileArgs;
+use crate::runner::{check_input, display_name, read_input};
+use crate::simulation::{Simulation, render};
+use crate::solution::registry;
+use clap::Args;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+#[derive(Args, Debug)]
+pub(crate) struct DebugArgs {
+    /// Year of the puzzle
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Input file, defaults to data/<year>/<profile>/dayNN.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Step through the example of the statement
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// What to do at the prompt of the debugger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    /// Advance this many steps, or until the end.
+    Steps(usize),
+    Quit,
+}
+
+impl Action {
+    /// Reads a command: an empty line or `s` for one step, a number for that
+    /// many steps, `r` to run to the end, `q` to quit.
+    fn parse(command: &str) -> Result<Action, String> {
+        match command.trim() {
+            "" | "s" => Ok(Action::Steps(1)),
+            "r" => Ok(Action::Steps(usize::MAX)),
+            "q" => Ok(Action::Quit),
+            count => count
+                .parse()
+                .map(Action::Steps)
+                .map_err(|_| format!("unknown command `{}`", count)),
+        }
+    }
+}
+
+/// Advances a simulation by up to `count` steps, returning the number of
+/// steps actually taken.
+fn advance(simulation: &mut dyn Simulation, count: usize) -> usize {
+    (0..count).take_while(|_| simulation.step()).count()
+}
+
+/// Steps through the simulation of a day, printing the grid with its frontier
+/// highlighted after each command read from the standard input.
+pub(crate) fn debug(args: &DebugArgs) -> Result<(), String> {
+    let registry = registry();
+    let Some(puzzle) = registry.get(&(args.year, args.day)) else {
+        return Err(format!(
+            "no solution registered for {} day {:02}",
+            args.year, args.day
+        ));
+    };
+    let Some(simulate) = puzzle.simulate else {
+        return Err(format!(
+            "{} day {:02} cannot be stepped through",
+            args.year, args.day
+        ));
+    };
+
+    let (path, input) = match (&args.input, args.example) {
+        (_, true) => (PathBuf::from("example"), puzzle.examples()[0].input.clone()),
+        (Some(path), false) => (path.clone(), check_input(puzzle, &read_input(path)?, path)),
+        (None, false) => {
+            let path = args.profile.layout().input(args.year, args.day);
+            let input = check_input(puzzle, &read_input(&path)?, &path);
+            (path, input)
+        }
+    };
+    let mut simulation = simulate(&input).map_err(|e| e.diagnostic(&display_name(&path)))?;
+
+    let color = std::io::stdout().is_terminal();
+    let mut step = 0;
+    let mut done = false;
+    let mut commands = std::io::stdin().lock().lines();
+    loop {
+        print!("{}", render(simulation.as_ref(), color));
+        println!("step {}: {}", step, simulation.status());
+        if done {
+            println!("the simulation is over");
+            return Ok(());
+        }
+        print!("[enter] step, <n> steps, r run to the end, q quit> ");
+        std::io::stdout().flush().map_err(|e| e.to_string())?;
+
+        let Some(command) = commands.next() else {
+            println!();
+            return Ok(());
+        };
+        let command = command.map_err(|e| format!("cannot read the command: {}", e))?;
+        let count = match Action::parse(&command) {
+            Ok(Action::Steps(count)) => count,
+            Ok(Action::Quit) => return Ok(()),
+            Err(message) => {
+                println!("{}", message);
+                continue;
+            }
+        };
+        let taken = advance(simulation.as_mut(), count);
+        step += taken;
+        done = taken < count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::debugger::{Action, advance};
+    use crate::solution::registry;
+
+    #[test]
+    fn commands() {
+        assert_eq!(Action::parse(""), Ok(Action::Steps(1)));
+        assert_eq!(Action::parse("s"), Ok(Action::Steps(1)));
+        assert_eq!(Action::parse(" 12\n"), Ok(Action::Steps(12)));
+        assert_eq!(Action::parse("r"), Ok(Action::Steps(usize::MAX)));
+        assert_eq!(Action::parse("q"), Ok(Action::Quit));
+        assert!(Action::parse("back").is_err());
+    }
+
+    #[test]
+    fn steps() {
+        let puzzle = registry()[&(2025, 4)];
+        let mut simulation = (puzzle.simulate.unwrap())(&puzzle.examples()[0].input).unwrap();
+
+        assert_eq!(advance(simulation.as_mut(), 3), 3);
+        assert_eq!(advance(simulation.as_mut(), usize::MAX), 6);
+        assert_eq!(advance(simulation.as_mut(), 1), 0);
+    }
+}
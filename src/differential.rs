@@ -0,0 +1,221 @@
+use crate::answer::Answer;
+use crate::runner::format_answer;
+use crate::solution::{Puzzle, registry};
+use crate::utils::random::Random;
+use clap::Args;
+use std::fmt::{Display, Formatter};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[derive(Args, Debug)]
+pub(crate) struct OracleArgs {
+    /// Year of the puzzles
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Only check this day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Number of random inputs of each size
+    #[arg(short, long, default_value_t = 100)]
+    cases: usize,
+
+    /// Largest size of the random inputs
+    #[arg(short, long, default_value_t = 8)]
+    max_size: usize,
+
+    /// Seed of the random inputs, defaults to one based on the time
+    #[arg(short, long)]
+    seed: Option<u64>,
+}
+
+/// An input on which the solution and its reference disagree.
+#[derive(Debug)]
+pub(crate) struct Disagreement {
+    pub(crate) part: u8,
+    pub(crate) size: usize,
+    /// Seed generating the input again at this size.
+    pub(crate) seed: u64,
+    pub(crate) input: String,
+    pub(crate) expected: Answer,
+    /// The answer of the solution, or why it has none.
+    pub(crate) actual: Result<Option<Answer>, String>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let actual = match &self.actual {
+            Ok(answer) => format_answer(answer.as_ref()),
+            Err(error) => error.clone(),
+        };
+        write!(
+            f,
+            "part {} disagrees on an input of size {} (seed {}):\n{}reference: {}, solution: {}",
+            self.part, self.size, self.seed, self.input, self.expected, actual
+        )
+    }
+}
+
+/// Runs the solution and its reference on the input generated from a seed,
+/// returning the first part with a reference answer on which they disagree.
+fn check(puzzle: &Puzzle, size: usize, seed: u64) -> Result<Option<Disagreement>, String> {
+    let generate = puzzle
+        .generate
+        .ok_or_else(|| String::from("no generator of random inputs"))?;
+    let input = generate(&mut Random::new(seed), size);
+    let reference = (puzzle.reference.unwrap())(&input)
+        .map_err(|e| format!("invalid random input, {}:\n{}", e, input))?;
+    let solution =
+        (puzzle.parse)(&input).map_err(|e| format!("invalid random input, {}:\n{}", e, input))?;
+    for part in [1, 2] {
+        let Some(expected) = reference.solve(part) else {
+            continue;
+        };
+        let actual = catch_unwind(AssertUnwindSafe(|| solution.solve(part)))
+            .map_err(|_| String::from("panicked"));
+        if actual.as_ref() != Ok(&Some(expected.clone())) {
+            return Ok(Some(Disagreement {
+                part,
+                size,
+                seed,
+                input,
+                expected,
+                actual,
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// Compares a solution with its reference on `cases` random inputs of each
+/// size from 1 to `max_size`, stopping at the first size with a disagreement:
+/// the shortest input of that size disagreeing is returned.
+pub(crate) fn compare(
+    puzzle: &Puzzle,
+    seed: u64,
+    cases: usize,
+    max_size: usize,
+) -> Result<Option<Disagreement>, String> {
+    let mut seeds = Random::new(seed);
+    for size in 1..=max_size {
+        let mut smallest: Option<Disagreement> = None;
+        for _ in 0..cases {
+            if let Some(disagreement) = check(puzzle, size, seeds.next_u64())?
+                && smallest
+                    .as_ref()
+                    .is_none_or(|smallest| disagreement.input.len() < smallest.input.len())
+            {
+                smallest = Some(disagreement);
+            }
+        }
+        if smallest.is_some() {
+            return Ok(smallest);
+        }
+    }
+    Ok(None)
+}
+
+/// Compares the days of a year that have a reference against it on random
+/// inputs, printing the smallest input each one disagrees on.
+pub(crate) fn oracle(args: &OracleArgs) -> Result<(), String> {
+    let seed = args.seed.unwrap_or_else(Random::time_seed);
+    println!("seed {}", seed);
+
+    let mut checked = 0;
+    let mut disagreements = 0;
+    for ((year, day), puzzle) in registry() {
+        if year != args.year || args.day.is_some_and(|d| d != day) || puzzle.reference.is_none() {
+            continue;
+        }
+
+        checked += 1;
+        match compare(&puzzle, seed, args.cases, args.max_size)
+            .map_err(|e| format!("{} day {:02}: {}", year, day, e))?
+        {
+            Some(disagreement) => {
+                disagreements += 1;
+                println!("{} day {:02} {}", year, day, disagreement);
+            }
+            None => println!(
+                "{} day {:02}: agrees on {} random inputs",
+                year,
+                day,
+                args.cases * args.max_size
+            ),
+        }
+    }
+
+    match (checked, disagreements) {
+        (0, _) => Err(format!("no reference registered for {}", args.year)),
+        (_, 0) => Ok(()),
+        (_, disagreements) => Err(format!(
+            "{} day(s) disagree with their reference",
+            disagreements
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::differential::compare;
+    use crate::solution::{Puzzle, Solution, boxed, registry};
+    use crate::utils::parse::ParseError;
+    use crate::utils::random::Random;
+    use crate::year2025::day03;
+
+    #[test]
+    fn references_agree() {
+        for ((year, day), puzzle) in registry() {
+            if puzzle.reference.is_some() {
+                let disagreement = compare(&puzzle, 2025, 50, 8).unwrap();
+                assert!(
+                    disagreement.is_none(),
+                    "{} day {:02} {}",
+                    year,
+                    day,
+                    disagreement.unwrap()
+                );
+            }
+        }
+    }
+
+    /// Day 3 ignoring the batteries after the 13th of each bank.
+    struct Truncated(Box<dyn Solution>);
+
+    impl Solution for Truncated {
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            let truncated: String = input
+                .lines()
+                .map(|bank| format!("{}\n", &bank[..bank.len().min(13)]))
+                .collect();
+            Ok(Truncated(boxed::<day03::BruteForce>(&truncated)?))
+        }
+
+        fn part1(&self) -> Option<Answer> {
+            self.0.part1()
+        }
+
+        fn part2(&self) -> Option<Answer> {
+            self.0.part2()
+        }
+    }
+
+    #[test]
+    fn smallest_disagreement() {
+        let puzzle = Puzzle::new::<Truncated>("")
+            .reference::<day03::BruteForce>()
+            .generator(day03::generate);
+
+        // Banks of size 1 have 13 batteries at most, so the first
+        // disagreements have 14.
+        let disagreement = compare(&puzzle, 7, 50, 6).unwrap().unwrap();
+        assert_eq!(disagreement.size, 2);
+        assert!(disagreement.input.lines().any(|bank| bank.len() == 14));
+        assert_ne!(disagreement.actual, Ok(Some(disagreement.expected.clone())));
+        assert_eq!(
+            day03::generate(&mut Random::new(disagreement.seed), 2),
+            disagreement.input
+        );
+    }
+}
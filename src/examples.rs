@@ -0,0 +1,280 @@
+use crate::answer::Answer;
+use regex::Regex;
+
+/// An example of a puzzle statement: its input and, when the statement
+/// gives it, the expected answer.
+#[derive(Clone, Debug)]
+pub struct Example {
+    pub input: String,
+    pub answer: Option<Answer>,
+}
+
+/// A piece of a puzzle statement.
+#[derive(Debug, PartialEq)]
+enum Chunk {
+    /// Wrapped text, joined on a single line.
+    Paragraph(String),
+    /// Indented lines, without their indentation: inputs, diagrams, explanations.
+    Block(String),
+    /// A bulleted list.
+    List,
+}
+
+/// Returns the puzzle statement of a source file: its first `/** ... */` comment.
+pub fn statement(source: &str) -> Option<&str> {
+    let start = source.find("/**")? + 3;
+    let end = start + source[start..].find("*/")?;
+    Some(&source[start..end])
+}
+
+/// Extracts the example of each part from the statement of a source file.
+///
+/// The example input of the first part is the first indented block of the
+/// statement. The second part reuses it, unless it introduces a new one with
+/// "For example:". The expected answers are read from the text, see `answer`.
+pub fn examples(source: &str) -> [Example; 2] {
+    let statement = statement(source).unwrap_or_default();
+    let (part_one, part_two) = statement
+        .split_once("--- Part Two ---")
+        .unwrap_or((statement, ""));
+    let part_one = chunks(part_one);
+    let part_two = chunks(part_two);
+
+    let input_one = first_block(&part_one).unwrap_or_default();
+    let input_two = match part_two.iter().position(|c| matches!(c, Chunk::Block(_))) {
+        Some(i) if i > 0 && ends_with(&part_two[i - 1], "For example:") => first_block(&part_two),
+        _ => None,
+    }
+    .unwrap_or_else(|| input_one.clone());
+
+    [
+        Example {
+            input: input_one,
+            answer: answer(&part_one),
+        },
+        Example {
+            input: input_two,
+            answer: answer(&part_two),
+        },
+    ]
+}
+
+fn chunks(text: &str) -> Vec<Chunk> {
+    let lines: Vec<&str> = text.lines().collect();
+    let is_blank = |line: &str| line.trim().is_empty();
+    let is_indented = |line: &str| line.starts_with("    ");
+    let is_item = |line: &str| line.starts_with("  - ");
+
+    let mut chunks = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if is_blank(lines[i]) {
+            i += 1;
+        } else if is_item(lines[i]) {
+            // Continuation lines of an item are indented like a block.
+            while i < lines.len() && (is_item(lines[i]) || is_indented(lines[i])) {
+                i += 1;
+            }
+            chunks.push(Chunk::List);
+        } else if is_indented(lines[i]) {
+            let mut block = Vec::new();
+            while i < lines.len() {
+                if is_indented(lines[i]) {
+                    block.push(&lines[i][4..]);
+                } else if is_blank(lines[i])
+                    && lines[i..]
+                        .iter()
+                        .find(|line| !is_blank(line))
+                        .is_some_and(|line| is_indented(line))
+                {
+                    // A blank line followed by more indented lines is part of the block.
+                    block.push("");
+                } else {
+                    break;
+                }
+                i += 1;
+            }
+            chunks.push(Chunk::Block(block.join("\n")));
+        } else {
+            let mut paragraph = Vec::new();
+            while i < lines.len()
+                && !is_blank(lines[i])
+                && !is_indented(lines[i])
+                && !is_item(lines[i])
+            {
+                paragraph.push(lines[i].trim());
+                i += 1;
+            }
+            chunks.push(Chunk::Paragraph(paragraph.join(" ")));
+        }
+    }
+    chunks
+}
+
+fn first_block(chunks: &[Chunk]) -> Option<String> {
+    chunks.iter().find_map(|chunk| match chunk {
+        Chunk::Block(block) => Some(block.clone()),
+        _ => None,
+    })
+}
+
+fn ends_with(chunk: &Chunk, suffix: &str) -> bool {
+    matches!(chunk, Chunk::Paragraph(paragraph) if paragraph.ends_with(suffix))
+}
+
+/// Reads the expected example answer of a part.
+///
+/// Statements give it in sentences like "the password in this example is 3",
+/// "split a total of 21 times", "98 + 89 + 78 + 92 = 357", "(5, 4, and 2)
+/// produces 40" or "only 2 paths". The answer is the last number of the last
+/// such sentence before the final question.
+fn answer(chunks: &[Chunk]) -> Option<Answer> {
+    let keywords = Regex::new(r"(?i)\b(example|total|only|produces)\b|=").unwrap();
+    let number = Regex::new(r"\d+").unwrap();
+    let sentence_end = Regex::new(r"[.!?]\s+").unwrap();
+
+    let mut paragraphs: Vec<&str> = chunks
+        .iter()
+        .filter_map(|chunk| match chunk {
+            Chunk::Paragraph(paragraph) => Some(paragraph.as_str()),
+            _ => None,
+        })
+        .collect();
+    if paragraphs.last().is_some_and(|p| p.ends_with('?')) {
+        paragraphs.pop();
+    }
+
+    paragraphs
+        .iter()
+        .rev()
+        .flat_map(|paragraph| {
+            sentence_end
+                .split(paragraph)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+        })
+        .filter(|sentence| keywords.is_match(sentence))
+        .find_map(|sentence| number.find_iter(sentence).last())
+        .map(|answer| answer.as_str().parse().unwrap())
+}
+
+/// The parts whose statement has no example answer: the last part of a year
+/// only asks to collect the other stars.
+#[cfg(test)]
+const WITHOUT_ANSWER: [(u16, u8, u8); 1] = [(2025, 12, 2)];
+
+/// Checks the solution of a part on its example against the expected answer,
+/// which must be found unless the part is in `WITHOUT_ANSWER`.
+#[cfg(test)]
+pub(crate) fn assert_example(year: u16, day: u8, part: u8) {
+    let puzzle = crate::solution::registry()[&(year, day)];
+    let example = &puzzle.examples()[part as usize - 1];
+
+    let solution = (puzzle.parse_example)(&example.input)
+        .unwrap_or_else(|e| panic!("{}", e.diagnostic("example")));
+    let answer = solution.solve(part);
+
+    let without_answer = WITHOUT_ANSWER.contains(&(year, day, part));
+    match &example.answer {
+        Some(_) if without_answer => panic!(
+            "{} day {:02} part {} has an example answer, remove it from WITHOUT_ANSWER",
+            year, day, part
+        ),
+        Some(_) => assert_eq!(
+            answer, example.answer,
+            "{} day {:02} part {} example",
+            year, day, part
+        ),
+        None => assert!(
+            without_answer,
+            "{} day {:02} part {}: no example answer found, register one with `example_answer`",
+            year, day, part
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::examples::{Chunk, chunks, examples, statement};
+
+    const SOURCE: &str = r#"use regex::Regex;
+
+/**
+--- Day 0: Test ---
+
+The list (your puzzle input) is
+wrapped. For example:
+
+    1 2
+    3
+
+    4
+
+It is explained:
+
+  - In 1 2, there are
+    two numbers.
+
+In this example, the total is 1 + 2 + 3 + 4 = 10. That's all:
+
+    1 + 2 + 3 + 4
+
+What is the total?
+
+--- Part Two ---
+
+Consider the example:
+
+    1 2
+
+In this example, only 2 lines matter.
+
+Be careful: 5 is not 6!
+
+What is the answer?
+*/
+fn main() {}
+"#;
+
+    #[test]
+    fn chunks_of_statement() {
+        let statement = statement(SOURCE).unwrap();
+        let (part_one, _) = statement.split_once("--- Part Two ---").unwrap();
+
+        let chunks = chunks(part_one);
+
+        assert_eq!(chunks.len(), 8);
+        assert_eq!(
+            chunks[1],
+            Chunk::Paragraph(String::from(
+                "The list (your puzzle input) is wrapped. For example:"
+            ))
+        );
+        assert_eq!(chunks[2], Chunk::Block(String::from("1 2\n3\n\n4")));
+        assert_eq!(chunks[4], Chunk::List);
+    }
+
+    #[test]
+    fn examples_of_statement() {
+        let [one, two] = examples(SOURCE);
+
+        assert_eq!(one.input, "1 2\n3\n\n4");
+        assert_eq!(one.answer, Some(Answer::from(10)));
+        assert_eq!(two.input, one.input);
+        assert_eq!(two.answer, Some(Answer::from(2)));
+    }
+
+    #[test]
+    fn examples_of_part_two() {
+        let source = "/**\nFor example:\n\n    1\n\nIt is 1.\n--- Part Two ---\nFor example:\n\n    2\n\nIn this example, it is 4.\n*/";
+
+        let [one, two] = examples(source);
+
+        assert_eq!(one.input, "1");
+        assert_eq!(one.answer, None);
+        assert_eq!(two.input, "2");
+        assert_eq!(two.answer, Some(Answer::from(4)));
+    }
+}
@@ -0,0 +1,346 @@
+use crate::answer::Answer;
+use crate::bench::format_duration;
+use crate::layout::ProfileArgs;
+use crate::manifest::Manifest;
+use crate::memory::Usage;
+use crate::report;
+use crate::runner::{answer_prefix, format_answer, memory_prefix, timings_prefix};
+use crate::solution::registry;
+use crate::verify::Status;
+use clap::Args;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+#[derive(Args, Debug)]
+pub(crate) struct RunAllArgs {
+    /// Year of the puzzles
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Number of days run at the same time, defaults to the number of CPUs
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+
+    /// Print the output of every part, not only of the failing ones
+    #[arg(short, long)]
+    output: bool,
+
+    /// Write a JSON report of the results to this file
+    #[arg(long)]
+    json: Option<PathBuf>,
+
+    /// Write a JUnit XML report of the results to this file
+    #[arg(long)]
+    junit: Option<PathBuf>,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// The outcome of running a part in its own process.
+pub(crate) struct Outcome {
+    pub(crate) year: u16,
+    pub(crate) day: u8,
+    pub(crate) part: u8,
+    pub(crate) answer: Option<Answer>,
+    pub(crate) expected: Option<Answer>,
+    /// The status, or why the part could not be run.
+    pub(crate) status: Result<Status, String>,
+    /// Wall time of the process.
+    pub(crate) time: Duration,
+    /// Parse and solve times measured by the process.
+    pub(crate) timings: Option<(Duration, Duration)>,
+    /// Memory used by the parse and the solve, with the counting allocator.
+    pub(crate) memory: Option<(Usage, Usage)>,
+    pub(crate) output: String,
+}
+
+/// Runs `f` on every task over a pool of `jobs` threads, returning the
+/// results in the order of the tasks, whatever the order they complete in.
+pub(crate) fn execute<T, R, F>(tasks: Vec<T>, jobs: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = tasks.len();
+    let queue = Mutex::new(tasks.into_iter().enumerate().collect::<VecDeque<_>>());
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            let sender = sender.clone();
+            let queue = &queue;
+            let f = &f;
+            scope.spawn(move || {
+                loop {
+                    let Some((index, task)) = queue.lock().unwrap().pop_front() else {
+                        break;
+                    };
+                    sender.send((index, f(task))).unwrap();
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<(usize, R)> = receiver.iter().collect();
+    results.sort_by_key(|(index, _)| *index);
+    assert_eq!(results.len(), count);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Runs every registered day and part of a year in parallel, each in its own
+/// process so its output is captured, then prints a summary ordered by day.
+pub(crate) fn run_all(args: &RunAllArgs) -> Result<(), String> {
+    let exe = std::env::current_exe().map_err(|e| format!("cannot find the runner: {}", e))?;
+    let manifest = Manifest::load(&args.profile.layout().answers(args.year))?;
+    let jobs = match args.jobs {
+        Some(jobs) => jobs as usize,
+        None => std::thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+
+    let tasks: Vec<(u8, u8)> = registry()
+        .keys()
+        .filter(|(year, _)| *year == args.year)
+        .flat_map(|(_, day)| [(*day, 1), (*day, 2)])
+        .collect();
+    if tasks.is_empty() {
+        return Err(format!("no solution registered for {}", args.year));
+    }
+
+    let start = Instant::now();
+    let outcomes = execute(tasks, jobs, |(day, part)| {
+        run_part(
+            &exe,
+            (args.year, day, part),
+            &args.profile.profile,
+            &manifest,
+        )
+    });
+    let elapsed = start.elapsed();
+
+    let mut counts = [0; 4];
+    println!("Day Part Status   {:>10}  Answer", "Time");
+    for outcome in &outcomes {
+        let (status, count) = match &outcome.status {
+            Ok(Status::Pass) => ("ok", 0),
+            Ok(Status::Fail) => ("FAIL", 1),
+            Ok(Status::Unknown) => ("unknown", 2),
+            Err(_) => ("ERROR", 3),
+        };
+        counts[count] += 1;
+        println!(
+            " {:02}    {} {:<8} {:>10}  {}",
+            outcome.day,
+            outcome.part,
+            status,
+            format_duration(outcome.time),
+            format_answer(outcome.answer.as_ref())
+        );
+    }
+
+    for outcome in &outcomes {
+        let failed = !matches!(outcome.status, Ok(Status::Pass | Status::Unknown));
+        if args.output || failed {
+            println!();
+            println!("--- day {:02} part {} ---", outcome.day, outcome.part);
+            if let Err(error) = &outcome.status {
+                println!("{}", error);
+            }
+            print!("{}", outcome.output);
+        }
+    }
+
+    println!();
+    println!(
+        "{} passed, {} failed, {} unknown, {} errors in {}",
+        counts[0],
+        counts[1],
+        counts[2],
+        counts[3],
+        format_duration(elapsed)
+    );
+
+    if let Some(path) = &args.json {
+        report::write(path, &report::json(&outcomes, elapsed))?;
+    }
+    if let Some(path) = &args.junit {
+        report::write(path, &report::junit(&outcomes, elapsed))?;
+    }
+    match counts[1] + counts[3] {
+        0 => Ok(()),
+        failures => Err(format!("{} part(s) did not pass", failures)),
+    }
+}
+
+fn run_part(
+    exe: &Path,
+    (year, day, part): (u16, u8, u8),
+    profile: &str,
+    manifest: &Manifest,
+) -> Outcome {
+    let start = Instant::now();
+    let result = Command::new(exe)
+        .args(["run", "--year", &year.to_string()])
+        .args(["--day", &day.to_string(), "--part", &part.to_string()])
+        .args(["--profile", profile, "--timings"])
+        .output();
+    let time = start.elapsed();
+
+    let expected = manifest.get(day, part).cloned();
+    let (answer, status, timings, memory, output) = match result {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let answer = parse_answer(&stdout, year, day, part);
+            let status = match (output.status.success(), &answer) {
+                (true, Some(answer)) => Ok(Status::of(answer.as_ref(), expected.as_ref())),
+                (true, None) => Err(String::from("no answer printed")),
+                (false, _) => Err(format!("exited with {}", output.status)),
+            };
+            let timings = parse_timings(&stdout, year, day, part);
+            let memory = parse_memory(&stdout, year, day, part);
+            (answer.flatten(), status, timings, memory, stdout + &stderr)
+        }
+        Err(e) => (
+            None,
+            Err(format!("cannot run {}: {}", exe.display(), e)),
+            None,
+            None,
+            String::new(),
+        ),
+    };
+
+    Outcome {
+        year,
+        day,
+        part,
+        answer,
+        expected,
+        status,
+        time,
+        timings,
+        memory,
+        output,
+    }
+}
+
+/// Finds the answer line of a part in the output of `run`: `None` when there
+/// is no such line, `Some(None)` when the part has no answer.
+pub(crate) fn parse_answer(stdout: &str, year: u16, day: u8, part: u8) -> Option<Option<Answer>> {
+    let prefix = answer_prefix(year, day, part);
+    let line = stdout
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix(&prefix))?;
+    match line {
+        "-" => Some(None),
+        answer => Some(answer.parse().ok()),
+    }
+}
+
+/// Finds the parse and solve times of a part in the output of `run --timings`.
+fn parse_timings(stdout: &str, year: u16, day: u8, part: u8) -> Option<(Duration, Duration)> {
+    let prefix = timings_prefix(year, day, part);
+    let line = stdout
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix(&prefix))?;
+    let (parse, solve) = line.split_once(", ")?;
+    let nanos = |field: &str, name: &str| {
+        let value = field.strip_prefix(name)?.strip_suffix(" ns")?;
+        value.parse().ok().map(Duration::from_nanos)
+    };
+    Some((nanos(parse, "parse ")?, nanos(solve, "solve ")?))
+}
+
+/// Finds the memory used by a part in the output of `run --timings`, when
+/// built with the counting allocator.
+fn parse_memory(stdout: &str, year: u16, day: u8, part: u8) -> Option<(Usage, Usage)> {
+    let prefix = memory_prefix(year, day, part);
+    let line = stdout
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix(&prefix))?;
+    let (parse, solve) = line.split_once(", ")?;
+    let parse = parse.strip_prefix("parse ")?.parse().ok()?;
+    let solve = solve.strip_prefix("solve ")?.parse().ok()?;
+    Some((parse, solve))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::executor::{execute, parse_answer, parse_memory, parse_timings};
+    use crate::memory::Usage;
+    use std::time::Duration;
+
+    #[test]
+    fn execute_in_order() {
+        let tasks: Vec<u64> = (0..8).collect();
+
+        // The first tasks are the slowest, so they complete last.
+        let results = execute(tasks, 4, |task| {
+            std::thread::sleep(Duration::from_millis(5 * (8 - task)));
+            task * 10
+        });
+
+        assert_eq!(results, vec![0, 10, 20, 30, 40, 50, 60, 70]);
+    }
+
+    #[test]
+    fn answer_of_output() {
+        let stdout = "debug line\n2025 day 07 part 1: 1587\n";
+
+        assert_eq!(
+            parse_answer(stdout, 2025, 7, 1),
+            Some(Some(Answer::from(1587)))
+        );
+        assert_eq!(parse_answer(stdout, 2025, 7, 2), None);
+        assert_eq!(
+            parse_answer("2025 day 12 part 2: -\n", 2025, 12, 2),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn timings_of_output() {
+        let stdout =
+            "2025 day 07 part 1: 1587\n2025 day 07 part 1 timings: parse 1200 ns, solve 35 ns\n";
+
+        assert_eq!(
+            parse_timings(stdout, 2025, 7, 1),
+            Some((Duration::from_nanos(1200), Duration::from_nanos(35)))
+        );
+        // The timings line is not mistaken for the answer line.
+        assert_eq!(
+            parse_answer(stdout, 2025, 7, 1),
+            Some(Some(Answer::from(1587)))
+        );
+        assert_eq!(
+            parse_timings("2025 day 07 part 1: 1587\n", 2025, 7, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn memory_of_output() {
+        let stdout = "2025 day 07 part 1 memory: parse peak=10 allocated=20 allocations=2, solve peak=0 allocated=8 allocations=1\n";
+        let usage = |peak, allocated, allocations| Usage {
+            peak,
+            allocated,
+            allocations,
+        };
+
+        assert_eq!(
+            parse_memory(stdout, 2025, 7, 1),
+            Some((usage(10, 20, 2), usage(0, 8, 1)))
+        );
+        assert_eq!(parse_memory(stdout, 2025, 7, 2), None);
+    }
+}
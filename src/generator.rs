@@ -0,0 +1,158 @@
+use crate::layout::ProfileArgs;
+use crate::solution::{Puzzle, registry};
+use crate::utils::random::Random;
+use crate::vault;
+use clap::Args;
+use std::path::Path;
+
+#[derive(Args, Debug)]
+pub(crate) struct GenerateArgs {
+    /// Year of the puzzles
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Only generate the input of this day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Size of the inputs, such as their number of lines
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+    size: u64,
+
+    /// Seed of the random inputs, defaults to one based on the time
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Replace the inputs already there
+    #[arg(short, long)]
+    force: bool,
+
+    /// Profile to write the inputs to, better not the one of the real inputs
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// Writes the random input of a puzzle to `path`, unless an input is already
+/// there and `force` is not set. Returns whether the input was written.
+pub(crate) fn write_input(
+    puzzle: &Puzzle,
+    size: usize,
+    seed: u64,
+    path: &Path,
+    force: bool,
+) -> Result<bool, String> {
+    let generate = puzzle
+        .generate
+        .ok_or_else(|| String::from("no generator of random inputs"))?;
+    if !force && vault::exists(path) {
+        return Ok(false);
+    }
+
+    let input = generate(&mut Random::new(seed), size);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("cannot create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, input).map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+    Ok(true)
+}
+
+/// Writes random inputs for the days of a year to the profile, each day from
+/// its own seed derived from the given one.
+pub(crate) fn generate(args: &GenerateArgs) -> Result<(), String> {
+    let seed = args.seed.unwrap_or_else(Random::time_seed);
+    println!("seed {}", seed);
+
+    let layout = args.profile.layout();
+    let mut written = 0;
+    for ((year, day), puzzle) in registry() {
+        if year != args.year || args.day.is_some_and(|d| d != day) {
+            continue;
+        }
+        if puzzle.generate.is_none() && args.day.is_none() {
+            continue;
+        }
+
+        let path = layout.input(year, day);
+        match write_input(
+            &puzzle,
+            args.size as usize,
+            seed.wrapping_add(day as u64),
+            &path,
+            args.force,
+        )
+        .map_err(|e| format!("{} day {:02}: {}", year, day, e))?
+        {
+            true => {
+                written += 1;
+                println!("wrote {}", path.display());
+            }
+            false => println!(
+                "{} already exists, use --force to replace it",
+                path.display()
+            ),
+        }
+    }
+
+    match written {
+        0 if args.day.is_none() => Err(format!("no input generated for {}", args.year)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::write_input;
+    use crate::sanity::check;
+    use crate::solution::registry;
+    use crate::utils::random::Random;
+    use crate::year2025::YEAR;
+
+    #[test]
+    fn every_day_generates() {
+        for ((year, day), puzzle) in registry() {
+            if year != YEAR {
+                continue;
+            }
+            let generate = puzzle
+                .generate
+                .unwrap_or_else(|| panic!("day {} has no generator", day));
+            for (size, seed) in [(1, 1), (3, 2), (8, 3), (100, 4)] {
+                let input = generate(&mut Random::new(seed), size);
+                assert_eq!(input, generate(&mut Random::new(seed), size));
+
+                let checked = check(&input, puzzle.shape);
+                assert!(
+                    checked.issues.iter().all(|issue| !issue.warned()),
+                    "day {:02}: {:?}\n{}",
+                    day,
+                    checked.issues,
+                    input
+                );
+                let solution = (puzzle.parse)(&input)
+                    .unwrap_or_else(|e| panic!("day {:02}: {}\n{}", day, e, input));
+                solution.part1();
+                solution.part2();
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_existing_inputs() {
+        let directory =
+            std::env::temp_dir().join(format!("rustaoc-generate-{}", std::process::id()));
+        let path = directory.join("2025").join("generated").join("day01.txt");
+        let puzzle = registry()[&(YEAR, 1)];
+
+        assert_eq!(write_input(&puzzle, 5, 7, &path, false), Ok(true));
+        let input = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(input.lines().count(), 5);
+
+        assert_eq!(write_input(&puzzle, 9, 8, &path, false), Ok(false));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), input);
+        assert_eq!(write_input(&puzzle, 9, 8, &path, true), Ok(true));
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 9);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,423 @@
+use clap::Args;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+/// Width of the wrapped statement, as in the existing days.
+const WIDTH: usize = 75;
+
+#[derive(Args, Debug)]
+pub(crate) struct ImportArgs {
+    /// Saved HTML page of the puzzle
+    page: PathBuf,
+
+    /// Year of the puzzle, read from the page title by default
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle, read from the page title by default
+    #[arg(short, long)]
+    day: Option<u8>,
+}
+
+/// A block of the puzzle statement.
+#[derive(Debug, PartialEq)]
+enum Block {
+    Text(String),
+    Code(String),
+    Item(String),
+}
+
+/// Reads the year and day from the title of a puzzle page.
+fn title(page: &str) -> Option<(u16, u8)> {
+    let regex = Regex::new(r"Day (\d+) - Advent of Code (\d+)").unwrap();
+    let captures = regex.captures(page)?;
+    Some((captures[2].parse().ok()?, captures[1].parse().ok()?))
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Splits the `<article>` elements of a puzzle page into blocks: headings and
+/// paragraphs, `<pre>` code blocks and list items. Inline markup is dropped.
+fn blocks(page: &str) -> Vec<Block> {
+    let tokens = Regex::new(r"(?s)<!--.*?-->|<(/?)([a-zA-Z0-9]+)[^>]*>|[^<]+").unwrap();
+
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    let mut kind = "p";
+    let mut articles = 0;
+    let mut flush = |kind: &mut &str, text: &mut String| {
+        let block = match *kind {
+            "pre" => Block::Code(text.trim_matches('\n').to_string()),
+            "li" => Block::Item(text.split_whitespace().collect::<Vec<_>>().join(" ")),
+            _ => Block::Text(text.split_whitespace().collect::<Vec<_>>().join(" ")),
+        };
+        if !text.trim().is_empty() {
+            blocks.push(block);
+        }
+        text.clear();
+        *kind = "p";
+    };
+
+    for token in tokens.captures_iter(page) {
+        let Some(tag) = token.get(2) else {
+            if articles > 0 && !token[0].starts_with("<!--") {
+                text.push_str(&decode(&token[0]));
+            }
+            continue;
+        };
+        let closing = &token[1] == "/";
+        let tag = tag.as_str().to_lowercase();
+
+        if tag == "article" {
+            flush(&mut kind, &mut text);
+            articles += if closing { -1 } else { 1 };
+            continue;
+        }
+        if articles == 0 {
+            continue;
+        }
+        match tag.as_str() {
+            // A paragraph inside a list item belongs to the item.
+            "p" if kind == "li" => text.push(' '),
+            "h1" | "h2" | "h3" | "p" | "pre" | "li" | "ul" | "ol" => {
+                flush(&mut kind, &mut text);
+                if !closing && tag != "ul" && tag != "ol" {
+                    kind = match tag.as_str() {
+                        "pre" => "pre",
+                        "li" => "li",
+                        _ => "p",
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+    blocks
+}
+
+/// Wraps a text at `WIDTH` columns, the first line starting with `first` and
+/// the next ones with `rest`.
+fn wrap(text: &str, first: &str, rest: &str) -> String {
+    let mut lines = Vec::new();
+    let mut line = String::from(first);
+    let mut empty = true;
+    for word in text.split_whitespace() {
+        if !empty && line.len() + 1 + word.len() > WIDTH {
+            lines.push(line);
+            line = String::from(rest);
+        } else if !empty {
+            line.push(' ');
+        }
+        line.push_str(word);
+        empty = false;
+    }
+    lines.push(line);
+    lines.join("\n")
+}
+
+/// Converts a saved puzzle page to the statement format of the doc comments:
+/// text wrapped at 75 columns, code blocks indented by four spaces, and list
+/// items starting with `  - `.
+pub(crate) fn statement(page: &str) -> Result<String, String> {
+    let blocks = blocks(page);
+    if blocks.is_empty() {
+        return Err(String::from("no puzzle statement in the page"));
+    }
+
+    let mut statement = String::new();
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            let list = matches!((&blocks[i - 1], block), (Block::Item(_), Block::Item(_)));
+            statement.push_str(if list { "\n" } else { "\n\n" });
+        }
+        let text = match block {
+            Block::Text(text) => wrap(text, "", ""),
+            Block::Item(text) => wrap(text, "  - ", "    "),
+            Block::Code(code) => code
+                .lines()
+                .map(|line| match line.trim_end() {
+                    "" => String::new(),
+                    line => format!("    {}", line),
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
+        statement.push_str(&text);
+    }
+    statement.push('\n');
+    // The statement ends up in a block comment, which must stay open.
+    Ok(statement.replace("*/", "* /"))
+}
+
+/// The source of a new day: the statement and a solution to fill in.
+fn skeleton(day: u8, statement: &str) -> String {
+    let name = format!("Day{:02}", day);
+    format!(
+        "use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::ParseError;
+
+/**
+{statement}*/
+pub struct {name} {{}}
+
+impl Solution for {name} {{
+    fn parse(_input: &str) -> Result<Self, ParseError> {{
+        Ok({name} {{}})
+    }}
+
+    fn part1(&self) -> Option<Answer> {{
+        None
+    }}
+
+    fn part2(&self) -> Option<Answer> {{
+        None
+    }}
+}}
+"
+    )
+}
+
+/// Registers a new day in the `mod.rs` of its year: declares its module, adds
+/// it to the registry and to the generated tests.
+fn register(source: &str, day: u8) -> Result<String, String> {
+    let module = format!("day{:02}", day);
+    let declaration = format!("pub mod {};", module);
+    if source.lines().any(|line| line == declaration) {
+        return Err(format!("{} is already registered", module));
+    }
+
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    // Declarations are sorted: insert before the first later day, or after the last one.
+    let declarations: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod day"))
+        .collect();
+    let Some(&last) = declarations.last() else {
+        return Err(String::from("no `pub mod dayNN;` declaration"));
+    };
+    let at = declarations
+        .iter()
+        .find(|&&i| lines[i].as_str() > declaration.as_str())
+        .map_or(last + 1, |&i| i);
+    lines.insert(at, declaration);
+
+    // Entries are sorted too: `((YEAR, N), ...),` on one line, or spread over
+    // several lines starting with `(` then `(YEAR, N),`.
+    let start = lines
+        .iter()
+        .position(|line| line.contains("BTreeMap::from(["))
+        .ok_or("no registry")?;
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.trim() == "])")
+        .map(|end| start + end)
+        .ok_or("no registry")?;
+    let entry_day = |line: &str| {
+        let line = line.trim().trim_start_matches('(');
+        line.strip_prefix("YEAR, ")?
+            .split_once(')')?
+            .0
+            .parse::<u8>()
+            .ok()
+    };
+    let registry = (start + 1..end)
+        .find(|&i| entry_day(&lines[i]).is_some_and(|d| d > day))
+        .map_or(end, |i| match lines[i].trim_start().starts_with("((") {
+            true => i,
+            false => i - 1,
+        });
+    lines.insert(
+        registry,
+        format!(
+            "        ((YEAR, {}), puzzle!({}::Day{:02})),",
+            day, module, day
+        ),
+    );
+
+    let start = lines
+        .iter()
+        .position(|line| line.contains("puzzle_tests!("))
+        .ok_or("no puzzle_tests! invocation")?;
+    let end = lines[start..]
+        .iter()
+        .position(|line| line == ");")
+        .map(|end| start + end)
+        .ok_or("no puzzle_tests! invocation")?;
+    let test = format!("    {}: {},", module, day);
+    let tests = (start + 1..end)
+        .find(|&i| lines[i].as_str() > test.as_str())
+        .unwrap_or(end);
+    lines.insert(tests, test);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates `<src>/yearYYYY/dayNN.rs` from a puzzle page and registers it.
+pub(crate) fn import_page(src: &Path, page: &str, year: u16, day: u8) -> Result<PathBuf, String> {
+    let directory = src.join(format!("year{}", year));
+    let registry = directory.join("mod.rs");
+    let path = directory.join(format!("day{:02}.rs", day));
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+
+    let source = std::fs::read_to_string(&registry)
+        .map_err(|e| format!("cannot read {}: {}", registry.display(), e))?;
+    let source = register(&source, day).map_err(|e| format!("{}: {}", registry.display(), e))?;
+    let statement = statement(page)?;
+
+    std::fs::write(&path, skeleton(day, &statement))
+        .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+    std::fs::write(&registry, source)
+        .map_err(|e| format!("cannot write {}: {}", registry.display(), e))?;
+    Ok(path)
+}
+
+pub(crate) fn import(args: &ImportArgs) -> Result<(), String> {
+    let page = std::fs::read_to_string(&args.page)
+        .map_err(|e| format!("cannot read {}: {}", args.page.display(), e))?;
+
+    let (year, day) = match (args.year, args.day, title(&page)) {
+        (Some(year), Some(day), _) => (year, day),
+        (year, day, Some((title_year, title_day))) => {
+            (year.unwrap_or(title_year), day.unwrap_or(title_day))
+        }
+        _ => {
+            return Err(String::from(
+                "no puzzle title in the page, give --year and --day",
+            ));
+        }
+    };
+
+    let path = import_page(Path::new("src"), &page, year, day)?;
+    println!("{} created", path.display());
+    println!("its example tests fail until part1 and part2 are implemented");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::examples::examples;
+    use crate::importer::{import_page, register, statement, title};
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 3 - Advent of Code 2025</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 3: Lobby ---</h2><p>You descend a short staircase, enter the surprisingly vast lobby, and are quickly cleared by the security checkpoint. When you get to the main elevator bank, however, you discover that each one has a red light above it: they're <em>all</em> offline.</p>
+<p>For example:</p>
+<pre><code>987654321111111
+811111111111119
+</code></pre>
+<ul>
+<li>In <code>987654321111111</code>, you can make the largest joltage possible, <code>98</code>, by turning on the first two batteries.</li>
+<li>In <code>811111111111119</code>, you can make <code>89</code>.</li>
+</ul>
+<p>The total output joltage is <code>98</code> + <code>89</code> = <code><em>187</em></code>.</p>
+<p>Find the maximum joltage possible from each bank; what is the <em>total output joltage</em>?</p>
+</article>
+<p>Your puzzle answer was <code>17142</code>.</p>
+</main>
+</body>
+</html>
+"#;
+
+    const STATEMENT: &str = "--- Day 3: Lobby ---
+
+You descend a short staircase, enter the surprisingly vast lobby, and are
+quickly cleared by the security checkpoint. When you get to the main
+elevator bank, however, you discover that each one has a red light above
+it: they're all offline.
+
+For example:
+
+    987654321111111
+    811111111111119
+
+  - In 987654321111111, you can make the largest joltage possible, 98, by
+    turning on the first two batteries.
+  - In 811111111111119, you can make 89.
+
+The total output joltage is 98 + 89 = 187.
+
+Find the maximum joltage possible from each bank; what is the total output
+joltage?
+";
+
+    const MOD: &str = "pub mod day01;
+pub mod day04;
+
+pub fn registry() -> BTreeMap<(u16, u8), Puzzle> {
+    BTreeMap::from([
+        ((YEAR, 1), puzzle!(day01::Day01)),
+        (
+            (YEAR, 4),
+            puzzle!(day04::Day04).shape(Shape::Grid),
+        ),
+    ])
+}
+
+#[cfg(test)]
+crate::solution::puzzle_tests!(crate::year2025::YEAR;
+    day01: 1,
+    day04: 4,
+);
+";
+
+    #[test]
+    fn page_title() {
+        assert_eq!(title(PAGE), Some((2025, 3)));
+        assert_eq!(title("<html></html>"), None);
+    }
+
+    #[test]
+    fn page_statement() {
+        assert_eq!(statement(PAGE).unwrap(), STATEMENT);
+        assert!(statement("<html><p>Not a puzzle</p></html>").is_err());
+    }
+
+    #[test]
+    fn register_day() {
+        let source = register(MOD, 3).unwrap();
+
+        assert!(source.starts_with("pub mod day01;\npub mod day03;\npub mod day04;\n"));
+        assert!(source.contains(
+            "        ((YEAR, 1), puzzle!(day01::Day01)),\n        ((YEAR, 3), puzzle!(day03::Day03)),\n        (\n            (YEAR, 4),"
+        ));
+        assert!(source.contains("    day01: 1,\n    day03: 3,\n    day04: 4,\n);"));
+        assert!(register(&source, 3).is_err());
+
+        let source = register(&source, 5).unwrap();
+        assert!(source.contains("        ),\n        ((YEAR, 5), puzzle!(day05::Day05)),\n    ])"));
+        assert!(source.contains("    day04: 4,\n    day05: 5,\n);"));
+    }
+
+    #[test]
+    fn import() {
+        let src = std::env::temp_dir().join(format!("rustaoc-import-{}", std::process::id()));
+        std::fs::create_dir_all(src.join("year2025")).unwrap();
+        std::fs::write(src.join("year2025").join("mod.rs"), MOD).unwrap();
+
+        let path = import_page(&src, PAGE, 2025, 3).unwrap();
+        assert!(import_page(&src, PAGE, 2025, 3).is_err());
+
+        // The generated day feeds its example to the generated tests.
+        let source = std::fs::read_to_string(path).unwrap();
+        let [example, _] = examples(&source);
+        assert_eq!(example.input, "987654321111111\n811111111111119");
+        assert_eq!(example.answer, Some(Answer::from(187)));
+
+        std::fs::remove_dir_all(src).unwrap();
+    }
+}
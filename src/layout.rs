@@ -0,0 +1,123 @@
+use crate::vault;
+use clap::Args;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the files of every year and profile.
+pub(crate) const DATA: &str = "data";
+
+/// Profile used when neither `--profile` nor `AOC_PROFILE` is given.
+pub(crate) const DEFAULT_PROFILE: &str = "default";
+
+/// Selects whose inputs and answers are used.
+#[derive(Args, Debug)]
+pub(crate) struct ProfileArgs {
+    /// Profile whose files are read from data/<year>/<profile>/
+    #[arg(long, env = "AOC_PROFILE", default_value = DEFAULT_PROFILE)]
+    pub(crate) profile: String,
+}
+
+impl ProfileArgs {
+    pub(crate) fn layout(&self) -> Layout {
+        Layout::new(DATA, &self.profile)
+    }
+}
+
+/// Where the files of a profile live. Each profile has its own directory per
+/// year, `<root>/<year>/<profile>/`, holding:
+///
+/// - `dayNN.txt`: the puzzle input of a day;
+/// - `dayNN.example.txt`: an example input replacing the one of the statement,
+///   `dayNN.example2.txt` replacing the one of the second part only;
+/// - `answers.txt`: the answers manifest, see `Manifest`;
+/// - `guesses.txt`: the submitted answers, see `Guesses`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Layout {
+    root: PathBuf,
+    profile: String,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout::new(DATA, DEFAULT_PROFILE)
+    }
+}
+
+impl Layout {
+    pub(crate) fn new(root: impl AsRef<Path>, profile: &str) -> Self {
+        Layout {
+            root: root.as_ref().to_path_buf(),
+            profile: profile.to_string(),
+        }
+    }
+
+    pub(crate) fn directory(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string()).join(&self.profile)
+    }
+
+    pub(crate) fn input(&self, year: u16, day: u8) -> PathBuf {
+        self.directory(year).join(format!("day{:02}.txt", day))
+    }
+
+    /// The example file of a part, when there is one.
+    pub(crate) fn example(&self, year: u16, day: u8, part: u8) -> Option<PathBuf> {
+        let directory = self.directory(year);
+        let part_example = directory.join(format!("day{:02}.example{}.txt", day, part));
+        let example = directory.join(format!("day{:02}.example.txt", day));
+        [part_example, example]
+            .into_iter()
+            .find(|path| vault::exists(path))
+    }
+
+    pub(crate) fn answers(&self, year: u16) -> PathBuf {
+        self.directory(year).join("answers.txt")
+    }
+
+    pub(crate) fn guesses(&self, year: u16) -> PathBuf {
+        self.directory(year).join("guesses.txt")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::Layout;
+    use std::path::Path;
+
+    #[test]
+    fn paths() {
+        let layout = Layout::new("data", "alice");
+
+        assert_eq!(
+            layout.input(2025, 7),
+            Path::new("data/2025/alice/day07.txt")
+        );
+        assert_eq!(
+            layout.answers(2024),
+            Path::new("data/2024/alice/answers.txt")
+        );
+        assert_eq!(
+            layout.guesses(2025),
+            Path::new("data/2025/alice/guesses.txt")
+        );
+        assert_eq!(
+            Layout::default().input(2025, 1),
+            Path::new("data/2025/default/day01.txt")
+        );
+    }
+
+    #[test]
+    fn examples() {
+        let root = std::env::temp_dir().join(format!("rustaoc-layout-{}", std::process::id()));
+        let layout = Layout::new(&root, "bob");
+        std::fs::create_dir_all(layout.directory(2025)).unwrap();
+
+        assert_eq!(layout.example(2025, 11, 1), None);
+        let example = layout.directory(2025).join("day11.example.txt");
+        let example2 = layout.directory(2025).join("day11.example2.txt");
+        std::fs::write(&example, "you: out\n").unwrap();
+        std::fs::write(&example2, "svr: out\n").unwrap();
+        assert_eq!(layout.example(2025, 11, 1), Some(example));
+        assert_eq!(layout.example(2025, 11, 2), Some(example2));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,346 @@
+use crate::progress::days_in;
+use clap::Args;
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Args, Debug)]
+pub(crate) struct LeaderboardArgs {
+    /// JSON export of the private leaderboard, as downloaded from its page
+    file: PathBuf,
+}
+
+/// The JSON export of a private leaderboard, of which only the stars are read.
+#[derive(Deserialize)]
+struct Export {
+    event: String,
+    members: BTreeMap<String, ExportMember>,
+}
+
+#[derive(Deserialize)]
+struct ExportMember {
+    id: u64,
+    name: Option<String>,
+    #[serde(default)]
+    local_score: u64,
+    #[serde(default)]
+    completion_day_level: BTreeMap<String, BTreeMap<String, ExportStar>>,
+}
+
+#[derive(Deserialize)]
+struct ExportStar {
+    get_star_ts: u64,
+    #[serde(default)]
+    star_index: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Member {
+    pub(crate) id: u64,
+    pub(crate) name: String,
+    /// The local score according to the export.
+    pub(crate) local_score: u64,
+    /// When each `(day, part)` star was obtained, and its index to break ties.
+    pub(crate) stars: BTreeMap<(u8, u8), (u64, u64)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Leaderboard {
+    pub(crate) year: u16,
+    pub(crate) members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub(crate) fn parse(json: &str) -> Result<Leaderboard, String> {
+        let export: Export = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let year = export
+            .event
+            .parse::<u16>()
+            .map_err(|_| format!("invalid event `{}`", export.event))?;
+
+        let mut members = Vec::new();
+        for member in export.members.into_values() {
+            let mut stars = BTreeMap::new();
+            for (day, parts) in member.completion_day_level {
+                let day = day
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|day| (1..=days_in(year)).contains(day))
+                    .ok_or_else(|| format!("member {}: invalid day `{}`", member.id, day))?;
+                for (part, star) in parts {
+                    let part = match part.as_str() {
+                        "1" => 1,
+                        "2" => 2,
+                        _ => return Err(format!("member {}: invalid part `{}`", member.id, part)),
+                    };
+                    stars.insert((day, part), (star.get_star_ts, star.star_index));
+                }
+            }
+            members.push(Member {
+                id: member.id,
+                name: member
+                    .name
+                    .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                local_score: member.local_score,
+                stars,
+            });
+        }
+        members.sort_by_key(|member| member.id);
+        Ok(Leaderboard { year, members })
+    }
+
+    /// The last day anyone has a star of.
+    pub(crate) fn last_day(&self) -> u8 {
+        self.members
+            .iter()
+            .flat_map(|member| member.stars.keys())
+            .map(|(day, _)| *day)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The points of each member for a star: the first to get it earns as
+    /// many points as there are members, the next one less, and so on.
+    pub(crate) fn points(&self, day: u8, part: u8) -> Vec<u64> {
+        let mut finishers: Vec<(u64, u64, usize)> = self
+            .members
+            .iter()
+            .enumerate()
+            .filter_map(|(i, member)| {
+                member
+                    .stars
+                    .get(&(day, part))
+                    .map(|(ts, index)| (*ts, *index, i))
+            })
+            .collect();
+        finishers.sort();
+
+        let mut points = vec![0; self.members.len()];
+        for (rank, (_, _, i)) in finishers.into_iter().enumerate() {
+            points[i] = (self.members.len() - rank) as u64;
+        }
+        points
+    }
+
+    /// The local score of each member after each day, from the first day to
+    /// the last one with a star.
+    pub(crate) fn scores(&self) -> Vec<Vec<u64>> {
+        let mut total = vec![0; self.members.len()];
+        let mut scores = Vec::new();
+        for day in 1..=self.last_day() {
+            for part in [1, 2] {
+                for (total, points) in total.iter_mut().zip(self.points(day, part)) {
+                    *total += points;
+                }
+            }
+            scores.push(total.clone());
+        }
+        scores
+    }
+
+    /// The time between the two stars of each member on each day, in seconds.
+    pub(crate) fn deltas(&self) -> Vec<Vec<Option<u64>>> {
+        self.members
+            .iter()
+            .map(|member| {
+                (1..=self.last_day())
+                    .map(|day| {
+                        let (first, _) = member.stars.get(&(day, 1))?;
+                        let (second, _) = member.stars.get(&(day, 2))?;
+                        Some(second.saturating_sub(*first))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// The rank of each score, equal scores sharing the same rank.
+pub(crate) fn ranks(scores: &[u64]) -> Vec<usize> {
+    scores
+        .iter()
+        .map(|score| 1 + scores.iter().filter(|other| *other > score).count())
+        .collect()
+}
+
+/// Formats a number of seconds as `h:mm:ss`.
+fn format_seconds(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Renders the markdown tables of a leaderboard: the final scores, the time
+/// taken by each member for the second part of each day, and the ranking
+/// after each day.
+pub(crate) fn render(leaderboard: &Leaderboard) -> String {
+    let scores = leaderboard.scores();
+    let last = scores
+        .last()
+        .cloned()
+        .unwrap_or_else(|| vec![0; leaderboard.members.len()]);
+    let final_ranks = ranks(&last);
+    let mut order: Vec<usize> = (0..leaderboard.members.len()).collect();
+    order.sort_by_key(|i| {
+        let member = &leaderboard.members[*i];
+        (final_ranks[*i], Reverse(member.stars.len()), member.id)
+    });
+
+    let days: Vec<String> = (1..=leaderboard.last_day())
+        .map(|day| format!("{:02}", day))
+        .collect();
+    let header = |first: &str| {
+        let mut header = format!("| {} |", first);
+        let mut separator = String::from("|---|");
+        for day in &days {
+            header.push_str(&format!(" {} |", day));
+            separator.push_str("---:|");
+        }
+        format!("{}\n{}\n", header, separator)
+    };
+
+    let mut output = format!("## {} local scores\n\n", leaderboard.year);
+    output.push_str("| Rank | Member | Stars | Score |\n");
+    output.push_str("|-----:|--------|------:|------:|\n");
+    for &i in &order {
+        let member = &leaderboard.members[i];
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            final_ranks[i],
+            member.name,
+            member.stars.len(),
+            last[i]
+        ));
+    }
+
+    output.push_str("\n## Time from part 1 to part 2\n\n");
+    output.push_str(&header("Member"));
+    let deltas = leaderboard.deltas();
+    for &i in &order {
+        let row: String = deltas[i]
+            .iter()
+            .map(|delta| format!(" {} |", delta.map_or(String::from("-"), format_seconds)))
+            .collect();
+        output.push_str(&format!("| {} |{}\n", leaderboard.members[i].name, row));
+    }
+
+    output.push_str("\n## Rank after each day\n\n");
+    output.push_str(&header("Member"));
+    let daily: Vec<Vec<usize>> = scores.iter().map(|scores| ranks(scores)).collect();
+    for &i in &order {
+        let mut row = String::new();
+        for (day, ranks) in daily.iter().enumerate() {
+            let change = match day {
+                0 => String::new(),
+                _ => match ranks[i] as i64 - daily[day - 1][i] as i64 {
+                    0 => String::from(" (=)"),
+                    change if change < 0 => format!(" (+{})", -change),
+                    change => format!(" (-{})", change),
+                },
+            };
+            row.push_str(&format!(" {}{} |", ranks[i], change));
+        }
+        output.push_str(&format!("| {} |{}\n", leaderboard.members[i].name, row));
+    }
+    output
+}
+
+/// Prints the tables of a private leaderboard export, warning about the
+/// members whose computed score differs from the one of the export.
+pub(crate) fn leaderboard(args: &LeaderboardArgs) -> Result<(), String> {
+    let json = std::fs::read_to_string(&args.file)
+        .map_err(|e| format!("cannot read {}: {}", args.file.display(), e))?;
+    let leaderboard =
+        Leaderboard::parse(&json).map_err(|e| format!("{}: {}", args.file.display(), e))?;
+    print!("{}", render(&leaderboard));
+
+    let last = leaderboard.scores().pop().unwrap_or_default();
+    for (member, score) in leaderboard.members.iter().zip(last) {
+        if member.local_score != score {
+            eprintln!(
+                "warning: {} has a local score of {} in the export, computed {}",
+                member.name, member.local_score, score
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leaderboard::{Leaderboard, format_seconds, ranks, render};
+
+    const LEADERBOARD_2025: &str = include_str!("../tests/fixtures/leaderboard2025.json");
+    const LEADERBOARD_2024: &str = include_str!("../tests/fixtures/leaderboard2024.json");
+
+    #[test]
+    fn parse_export() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD_2025).unwrap();
+
+        assert_eq!(leaderboard.year, 2025);
+        let names: Vec<&str> = leaderboard
+            .members
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, ["Alice", "Bob", "(anonymous user #3)", "Dana"]);
+        assert_eq!(leaderboard.members[2].stars.len(), 5);
+        assert_eq!(leaderboard.last_day(), 3);
+
+        let error = Leaderboard::parse(
+            &LEADERBOARD_2025.replace("\"3\": {\n          \"1\"", "\"13\": {\n          \"1\""),
+        );
+        assert_eq!(error, Err(String::from("member 1: invalid day `13`")));
+        assert!(Leaderboard::parse("{\"event\": \"2025\"}").is_err());
+    }
+
+    #[test]
+    fn local_scores() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD_2025).unwrap();
+
+        let scores = leaderboard.scores();
+        assert_eq!(
+            scores,
+            [vec![7, 7, 2, 0], vec![11, 14, 9, 0], vec![17, 14, 17, 0]]
+        );
+        let exported: Vec<u64> = leaderboard.members.iter().map(|m| m.local_score).collect();
+        assert_eq!(scores[2], exported);
+
+        // Stars obtained the same second are ordered by their index.
+        let leaderboard = Leaderboard::parse(LEADERBOARD_2024).unwrap();
+        assert_eq!(leaderboard.points(5, 1), [1, 2]);
+        assert_eq!(leaderboard.scores().pop().unwrap(), [1, 4]);
+    }
+
+    #[test]
+    fn deltas_and_ranks() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD_2025).unwrap();
+
+        assert_eq!(
+            leaderboard.deltas(),
+            [
+                vec![Some(300), Some(3000), Some(300)],
+                vec![Some(700), Some(300), None],
+                vec![None, Some(1700), Some(50)],
+                vec![None, None, None],
+            ]
+        );
+        assert_eq!(ranks(&[7, 7, 2, 0]), [1, 1, 3, 4]);
+        assert_eq!(format_seconds(3725), "1:02:05");
+    }
+
+    #[test]
+    fn tables() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD_2025).unwrap();
+
+        let tables = render(&leaderboard);
+        assert!(tables.contains("| 1 | Alice | 6 | 17 |\n| 1 | (anonymous user #3) | 5 | 17 |\n| 3 | Bob | 4 | 14 |\n| 4 | Dana | 0 | 0 |\n"));
+        assert!(tables.contains("| Bob | 0:11:40 | 0:05:00 | - |\n"));
+        assert!(tables.contains("| Alice | 1 | 2 (-1) | 1 (+1) |\n"));
+        assert!(tables.contains("| (anonymous user #3) | 3 | 3 (=) | 1 (+2) |\n"));
+    }
+}
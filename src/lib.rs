@@ -0,0 +1,33 @@
+//! Advent of Code solutions and the tools around them.
+//!
+//! The public API is made of the shared helpers of `utils`, the solutions of
+//! each year (`year2025`, ...) with the `Solution` trait they implement, and
+//! the registry of every puzzle. The `cli` module is the command line of the
+//! binary.
+
+pub mod answer;
+mod bench;
+pub mod cli;
+mod client;
+mod debugger;
+mod differential;
+pub mod examples;
+mod executor;
+mod generator;
+mod importer;
+mod layout;
+mod leaderboard;
+mod manifest;
+pub mod memory;
+mod progress;
+mod report;
+mod runner;
+mod sanity;
+pub mod simulation;
+pub mod solution;
+mod submit;
+pub mod utils;
+mod vault;
+mod verify;
+mod watch;
+pub mod year2025;
@@ -1,6 +1,13 @@
-mod utils;
-mod year2025;
+use clap::Parser;
+use rust_aoc::cli::Cli;
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOCATOR: rust_aoc::memory::Counting = rust_aoc::memory::Counting;
 
 fn main() {
-    println!("Hello, world!");
+    if let Err(message) = Cli::parse().run() {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,79 @@
+use crate::answer::Answer;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The expected answers of a year, keyed by day and part.
+///
+/// The manifest is a plain text file with one `<day> <part> <answer>` entry
+/// per line; blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub(crate) struct Manifest {
+    answers: BTreeMap<(u8, u8), Answer>,
+}
+
+impl Manifest {
+    pub(crate) fn parse(content: &str) -> Result<Manifest, String> {
+        let mut answers = BTreeMap::new();
+        for (n, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let (Some(day), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!("line {}: expected `<day> <part> <answer>`", n + 1));
+            };
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| format!("line {}: invalid day {}", n + 1, day))?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(format!("line {}: invalid part {}", n + 1, part)),
+            };
+            let Ok(answer) = answer.trim().parse::<Answer>();
+            answers.insert((day, part), answer);
+        }
+        Ok(Manifest { answers })
+    }
+
+    /// Loads a manifest, a missing file being an empty manifest.
+    pub(crate) fn load(path: &Path) -> Result<Manifest, String> {
+        if !path.exists() {
+            return Ok(Manifest::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        Manifest::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub(crate) fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::manifest::Manifest;
+
+    #[test]
+    fn parse() {
+        let manifest = Manifest::parse("# comment\n1 1 1031\n\n1 2 5831\n12 1 ABC DEF\n").unwrap();
+
+        assert_eq!(manifest.get(1, 1), Some(&Answer::from(1031)));
+        assert_eq!(manifest.get(1, 2), Some(&Answer::from(5831)));
+        assert_eq!(manifest.get(12, 1), Some(&Answer::from("ABC DEF")));
+        assert_eq!(manifest.get(12, 2), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Manifest::parse("1 1").is_err());
+        assert!(Manifest::parse("x 1 12").is_err());
+        assert!(Manifest::parse("1 3 12").is_err());
+    }
+}
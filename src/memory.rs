@@ -0,0 +1,187 @@
+//! Memory used by the solutions, as seen by a counting global allocator.
+//!
+//! The binary only installs `Counting` with the `count-alloc` feature;
+//! without it every `Usage` is zero and `enabled` is false.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting what goes through it.
+pub struct Counting;
+
+fn record(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+// SAFETY: every call is forwarded to the system allocator unchanged, the
+// counters being updated on the side.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc(layout) };
+        if !pointer.is_null() {
+            record(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc_zeroed(layout) };
+        if !pointer.is_null() {
+            record(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// A reallocation counts as a new allocation of the new size.
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let moved = unsafe { System.realloc(pointer, layout, new_size) };
+        if !moved.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record(new_size);
+        }
+        moved
+    }
+}
+
+/// What a step allocated: the most bytes held at once on top of what was
+/// held before it, the bytes allocated in total and the number of allocations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub peak: usize,
+    pub allocated: usize,
+    pub allocations: usize,
+}
+
+/// Written as `peak=<bytes> allocated=<bytes> allocations=<count>`.
+impl Display for Usage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak={} allocated={} allocations={}",
+            self.peak, self.allocated, self.allocations
+        )
+    }
+}
+
+impl FromStr for Usage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut usage = Usage::default();
+        for field in s.split_whitespace() {
+            let (name, value) = field
+                .split_once('=')
+                .ok_or_else(|| format!("invalid field `{}`", field))?;
+            let value = value
+                .parse()
+                .map_err(|_| format!("invalid value in `{}`", field))?;
+            match name {
+                "peak" => usage.peak = value,
+                "allocated" => usage.allocated = value,
+                "allocations" => usage.allocations = value,
+                _ => return Err(format!("unknown field `{}`", name)),
+            }
+        }
+        Ok(usage)
+    }
+}
+
+/// Whether the counting allocator is installed.
+pub fn enabled() -> bool {
+    cfg!(feature = "count-alloc")
+}
+
+/// Runs `f`, returning its result and what it allocated. The counters are
+/// global: allocations of other threads meanwhile are counted too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    };
+    (result, usage)
+}
+
+/// Formats a number of bytes with a binary unit suited to its magnitude.
+pub fn format_bytes(bytes: usize) -> String {
+    if bytes < 1 << 10 {
+        format!("{} B", bytes)
+    } else if bytes < 1 << 20 {
+        format!("{:.1} KiB", bytes as f64 / (1 << 10) as f64)
+    } else if bytes < 1 << 30 {
+        format!("{:.1} MiB", bytes as f64 / (1 << 20) as f64)
+    } else {
+        format!("{:.2} GiB", bytes as f64 / (1 << 30) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memory::{Counting, Usage, format_bytes, measure};
+    use std::alloc::{GlobalAlloc, Layout};
+
+    #[test]
+    fn counting() {
+        // The test binary does not install the allocator: only the calls
+        // below are counted.
+        let ((), usage) = measure(|| unsafe {
+            let small = Layout::from_size_align(100, 8).unwrap();
+            let a = Counting.alloc(small);
+            let b = Counting.alloc_zeroed(small);
+            Counting.dealloc(a, small);
+            let b = Counting.realloc(b, small, 300);
+            Counting.dealloc(b, Layout::from_size_align(300, 8).unwrap());
+        });
+
+        assert_eq!(
+            usage,
+            Usage {
+                peak: 300,
+                allocated: 500,
+                allocations: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn usages() {
+        let usage = Usage {
+            peak: 1024,
+            allocated: 2048,
+            allocations: 12,
+        };
+        assert_eq!(usage.to_string(), "peak=1024 allocated=2048 allocations=12");
+        assert_eq!(usage.to_string().parse(), Ok(usage));
+        assert!("peak=x".parse::<Usage>().is_err());
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(12), "12 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 << 20), "5.0 MiB");
+        assert_eq!(format_bytes(3 << 30), "3.00 GiB");
+    }
+}
@@ -0,0 +1,304 @@
+use crate::bench::{format_duration, measure};
+use crate::layout::{Layout, ProfileArgs};
+use crate::manifest::Manifest;
+use crate::runner::{check_input, parse, read_input};
+use crate::solution::{Puzzle, registry};
+use crate::verify::Status;
+use clap::Args;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+#[derive(Args, Debug)]
+pub(crate) struct ProgressArgs {
+    /// Years to show, defaults to every year with a registered solution
+    #[arg(short, long)]
+    year: Vec<u16>,
+
+    /// Number of repetitions of each part, the best runtime being shown
+    #[arg(short, long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// Where a part stands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Star {
+    /// The answer of the solution is the one of the manifest.
+    Verified,
+    /// The solution has an answer, but the manifest has none to check it.
+    Solved,
+    /// The answer differs from the manifest, or the input cannot be parsed.
+    Failing,
+    /// There is no solution, no input, or no answer.
+    Missing,
+}
+
+impl Star {
+    fn symbol(self) -> char {
+        match self {
+            Star::Verified => '*',
+            Star::Solved => '+',
+            Star::Failing => 'x',
+            Star::Missing => '.',
+        }
+    }
+}
+
+/// Where a day stands: its parts and the best time taken to parse and solve both.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DayProgress {
+    pub(crate) stars: [Star; 2],
+    pub(crate) best: Option<Duration>,
+}
+
+impl Default for DayProgress {
+    fn default() -> Self {
+        DayProgress {
+            stars: [Star::Missing; 2],
+            best: None,
+        }
+    }
+}
+
+/// The number of days of the calendar of a year: 25 until 2024, then 12.
+pub(crate) fn days_in(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// The day of the week of December 1st, 0 being Monday.
+fn first_weekday(year: u16) -> usize {
+    // Sakamoto's method, which counts from Sunday.
+    let y = year as usize;
+    let sunday_based = (y + y / 4 - y / 100 + y / 400 + 4 + 1) % 7;
+    (sunday_based + 6) % 7
+}
+
+/// Runs a registered day on the input of the profile.
+fn day_progress(
+    layout: &Layout,
+    manifest: &Manifest,
+    year: u16,
+    day: u8,
+    puzzle: &Puzzle,
+    runs: u32,
+) -> DayProgress {
+    let path = layout.input(year, day);
+    let Ok(input) = read_input(&path) else {
+        return DayProgress::default();
+    };
+    let input = check_input(puzzle, &input, &path);
+    let (parsing, solution) = measure(runs, || parse(puzzle.parse, &input, &path));
+    let Ok(solution) = solution else {
+        return DayProgress {
+            stars: [Star::Failing; 2],
+            best: None,
+        };
+    };
+
+    let mut stars = [Star::Missing; 2];
+    let mut best = parsing.min();
+    for part in [1, 2] {
+        let (timings, answer) = measure(runs, || solution.solve(part));
+        best += timings.min();
+        let expected = manifest.get(day, part);
+        stars[part as usize - 1] = match Status::of(answer.as_ref(), expected) {
+            Status::Pass if answer.is_none() => Star::Missing,
+            Status::Pass => Star::Verified,
+            Status::Unknown => Star::Solved,
+            Status::Fail => Star::Failing,
+        };
+    }
+    DayProgress {
+        stars,
+        best: Some(best),
+    }
+}
+
+/// Gives the second star of the last day, which has no puzzle, once every
+/// other star is verified.
+fn last_star(year: u16, days: &mut BTreeMap<u8, DayProgress>) {
+    let last = days_in(year);
+    let mut others = (1..=last).flat_map(|day| {
+        let stars = days.get(&day).map_or([Star::Missing; 2], |d| d.stars);
+        let count = if day == last { 1 } else { 2 };
+        stars.into_iter().take(count)
+    });
+    if others.all(|star| star == Star::Verified)
+        && let Some(progress) = days.get_mut(&last)
+        && progress.stars[1] == Star::Missing
+    {
+        progress.stars[1] = Star::Verified;
+    }
+}
+
+const CELL: usize = 10;
+
+/// Renders the calendar of a year, one week per row.
+pub(crate) fn render(year: u16, days: &BTreeMap<u8, DayProgress>) -> String {
+    let count = |star: Star| {
+        days.values()
+            .flat_map(|d| d.stars)
+            .filter(|s| *s == star)
+            .count()
+    };
+    let total = 2 * days_in(year) as usize;
+    let mut lines = vec![format!(
+        "{}: {}/{} stars ({} verified, {} solved, {} failing)",
+        year,
+        count(Star::Verified) + count(Star::Solved),
+        total,
+        count(Star::Verified),
+        count(Star::Solved),
+        count(Star::Failing)
+    )];
+
+    let border = format!("+{}", format!("{}+", "-".repeat(CELL)).repeat(7));
+    lines.push(border.clone());
+    let names = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+    lines.push(format!(
+        "|{}",
+        names
+            .map(|name| format!(" {:<width$}|", name, width = CELL - 1))
+            .concat()
+    ));
+    lines.push(border.clone());
+
+    let offset = first_weekday(year);
+    let cells: Vec<Option<u8>> = (0..offset)
+        .map(|_| None)
+        .chain((1..=days_in(year)).map(Some))
+        .collect();
+    for week in cells.chunks(7) {
+        let mut top = String::from("|");
+        let mut bottom = String::from("|");
+        for cell in week.iter().copied().chain(std::iter::repeat(None)).take(7) {
+            let (label, time) = match cell {
+                Some(day) => {
+                    let progress = days.get(&day).cloned().unwrap_or_default();
+                    let stars: String = progress.stars.iter().map(|s| s.symbol()).collect();
+                    let time = progress.best.map(format_duration).unwrap_or_default();
+                    (format!("{:02} {}", day, stars), time)
+                }
+                None => (String::new(), String::new()),
+            };
+            top.push_str(&format!(" {:<width$}|", label, width = CELL - 1));
+            bottom.push_str(&format!(" {:<width$}|", time, width = CELL - 1));
+        }
+        lines.push(top);
+        lines.push(bottom);
+        lines.push(border.clone());
+    }
+    lines.push(String::from("* verified  + solved  x failing  . missing"));
+    lines.join("\n") + "\n"
+}
+
+/// Prints the calendar of each year, running every registered day on the
+/// input of the profile to check it against the answers manifest.
+pub(crate) fn progress(args: &ProgressArgs) -> Result<(), String> {
+    let registry = registry();
+    let layout = args.profile.layout();
+    let years: BTreeSet<u16> = match args.year.is_empty() {
+        true => registry.keys().map(|(year, _)| *year).collect(),
+        false => args.year.iter().copied().collect(),
+    };
+
+    for year in years {
+        let manifest = Manifest::load(&layout.answers(year))?;
+        let mut days = BTreeMap::new();
+        for ((_, day), puzzle) in registry.range((year, 1)..=(year, days_in(year))) {
+            let progress = day_progress(&layout, &manifest, year, *day, puzzle, args.runs);
+            days.insert(*day, progress);
+        }
+        last_star(year, &mut days);
+        println!("{}", render(year, &days));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::progress::{DayProgress, Star, days_in, first_weekday, last_star, render};
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    #[test]
+    fn calendars() {
+        assert_eq!(days_in(2015), 25);
+        assert_eq!(days_in(2024), 25);
+        assert_eq!(days_in(2025), 12);
+        // December 1st was a Tuesday in 2015 and a Monday in 2025.
+        assert_eq!(first_weekday(2015), 1);
+        assert_eq!(first_weekday(2024), 6);
+        assert_eq!(first_weekday(2025), 0);
+    }
+
+    #[test]
+    fn last_day() {
+        let verified = DayProgress {
+            stars: [Star::Verified; 2],
+            best: None,
+        };
+        let mut days: BTreeMap<u8, DayProgress> =
+            (1..=11).map(|day| (day, verified.clone())).collect();
+        days.insert(
+            12,
+            DayProgress {
+                stars: [Star::Verified, Star::Missing],
+                best: None,
+            },
+        );
+        last_star(2025, &mut days);
+        assert_eq!(days[&12].stars, [Star::Verified; 2]);
+
+        days.get_mut(&12).unwrap().stars[1] = Star::Missing;
+        days.get_mut(&3).unwrap().stars[0] = Star::Solved;
+        last_star(2025, &mut days);
+        assert_eq!(days[&12].stars, [Star::Verified, Star::Missing]);
+    }
+
+    #[test]
+    fn rendering() {
+        let days = BTreeMap::from([
+            (
+                1,
+                DayProgress {
+                    stars: [Star::Verified, Star::Solved],
+                    best: Some(Duration::from_micros(1500)),
+                },
+            ),
+            (
+                2,
+                DayProgress {
+                    stars: [Star::Failing, Star::Missing],
+                    best: Some(Duration::from_nanos(800)),
+                },
+            ),
+        ]);
+
+        let calendar = render(2025, &days);
+        let lines: Vec<&str> = calendar.lines().collect();
+        assert_eq!(
+            lines[0],
+            "2025: 2/24 stars (1 verified, 1 solved, 1 failing)"
+        );
+        assert_eq!(
+            lines[2],
+            "| Mo       | Tu       | We       | Th       | Fr       | Sa       | Su       |"
+        );
+        assert_eq!(
+            lines[4],
+            "| 01 *+    | 02 x.    | 03 ..    | 04 ..    | 05 ..    | 06 ..    | 07 ..    |"
+        );
+        assert_eq!(
+            lines[5],
+            "| 1.5 ms   | 800 ns   |          |          |          |          |          |"
+        );
+        assert_eq!(
+            lines[7],
+            "| 08 ..    | 09 ..    | 10 ..    | 11 ..    | 12 ..    |          |          |"
+        );
+        assert_eq!(lines.len(), 11);
+    }
+}
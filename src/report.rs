@@ -0,0 +1,310 @@
+use crate::answer::Answer;
+use crate::executor::Outcome;
+use crate::memory::Usage;
+use crate::runner::format_answer;
+use crate::verify::Status;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// The name of the status of an outcome in the reports.
+fn status_name(outcome: &Outcome) -> &'static str {
+    match outcome.status {
+        Ok(Status::Pass) => "pass",
+        Ok(Status::Fail) => "fail",
+        Ok(Status::Unknown) => "unknown",
+        Err(_) => "error",
+    }
+}
+
+/// A part of the JSON report.
+#[derive(Serialize)]
+struct JsonResult<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    expected: Option<String>,
+    status: &'static str,
+    error: Option<&'a str>,
+    time_ns: u128,
+    parse_ns: Option<u128>,
+    solve_ns: Option<u128>,
+    parse_memory: Option<JsonUsage>,
+    solve_memory: Option<JsonUsage>,
+}
+
+#[derive(Serialize)]
+struct JsonUsage {
+    peak_bytes: usize,
+    allocated_bytes: usize,
+    allocations: usize,
+}
+
+impl From<Usage> for JsonUsage {
+    fn from(usage: Usage) -> Self {
+        JsonUsage {
+            peak_bytes: usage.peak,
+            allocated_bytes: usage.allocated,
+            allocations: usage.allocations,
+        }
+    }
+}
+
+/// The count of each status over the report.
+#[derive(Serialize)]
+struct JsonSummary {
+    pass: usize,
+    fail: usize,
+    unknown: usize,
+    error: usize,
+    time_ns: u128,
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    results: Vec<JsonResult<'a>>,
+    summary: JsonSummary,
+}
+
+/// A JSON report: every part with its answer, expected answer, status and
+/// timings, then the count of each status.
+pub(crate) fn json(outcomes: &[Outcome], elapsed: Duration) -> String {
+    let results = outcomes
+        .iter()
+        .map(|outcome| JsonResult {
+            year: outcome.year,
+            day: outcome.day,
+            part: outcome.part,
+            answer: outcome.answer.as_ref().map(Answer::to_string),
+            expected: outcome.expected.as_ref().map(Answer::to_string),
+            status: status_name(outcome),
+            error: outcome.status.as_ref().err().map(String::as_str),
+            time_ns: outcome.time.as_nanos(),
+            parse_ns: outcome.timings.map(|(parse, _)| parse.as_nanos()),
+            solve_ns: outcome.timings.map(|(_, solve)| solve.as_nanos()),
+            parse_memory: outcome.memory.map(|(parse, _)| parse.into()),
+            solve_memory: outcome.memory.map(|(_, solve)| solve.into()),
+        })
+        .collect();
+    let count = |name: &str| outcomes.iter().filter(|o| status_name(o) == name).count();
+    let report = JsonReport {
+        results,
+        summary: JsonSummary {
+            pass: count("pass"),
+            fail: count("fail"),
+            unknown: count("unknown"),
+            error: count("error"),
+            time_ns: elapsed.as_nanos(),
+        },
+    };
+    serde_json::to_string_pretty(&report).unwrap() + "\n"
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters are not allowed in XML 1.0, even escaped.
+            c if (c as u32) < 0x20 && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A JUnit XML report: a test suite per year and a test case per part, the
+/// parts without a known answer being skipped.
+pub(crate) fn junit(outcomes: &[Outcome], elapsed: Duration) -> String {
+    let mut years: BTreeMap<u16, Vec<&Outcome>> = BTreeMap::new();
+    for outcome in outcomes {
+        years.entry(outcome.year).or_default().push(outcome);
+    }
+    let count = |outcomes: &[&Outcome], name: &str| {
+        outcomes.iter().filter(|o| status_name(o) == name).count()
+    };
+    let all: Vec<&Outcome> = outcomes.iter().collect();
+
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        report,
+        "<testsuites name=\"aoc\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+        all.len(),
+        count(&all, "fail"),
+        count(&all, "error"),
+        count(&all, "unknown"),
+        elapsed.as_secs_f64()
+    )
+    .unwrap();
+    for (year, outcomes) in years {
+        let time: Duration = outcomes.iter().map(|o| o.time).sum();
+        writeln!(
+            report,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+            year,
+            outcomes.len(),
+            count(&outcomes, "fail"),
+            count(&outcomes, "error"),
+            count(&outcomes, "unknown"),
+            time.as_secs_f64()
+        )
+        .unwrap();
+        for outcome in outcomes {
+            write!(
+                report,
+                "    <testcase classname=\"{}.day{:02}\" name=\"part {}\" time=\"{:.6}\"",
+                year,
+                outcome.day,
+                outcome.part,
+                outcome.time.as_secs_f64()
+            )
+            .unwrap();
+            let answer = xml_escape(&format_answer(outcome.answer.as_ref()));
+            match &outcome.status {
+                Ok(Status::Pass) => report.push_str("/>\n"),
+                Ok(Status::Fail) => {
+                    let expected = format_answer(outcome.expected.as_ref());
+                    writeln!(
+                        report,
+                        ">\n      <failure message=\"expected {}, got {}\"/>\n    </testcase>",
+                        xml_escape(&expected),
+                        answer
+                    )
+                    .unwrap();
+                }
+                Ok(Status::Unknown) => {
+                    writeln!(
+                        report,
+                        ">\n      <skipped message=\"no known answer, got {}\"/>\n    </testcase>",
+                        answer
+                    )
+                    .unwrap();
+                }
+                Err(error) => {
+                    writeln!(
+                        report,
+                        ">\n      <error message=\"{}\">{}</error>\n    </testcase>",
+                        xml_escape(error),
+                        xml_escape(&outcome.output)
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        report.push_str("  </testsuite>\n");
+    }
+    report.push_str("</testsuites>\n");
+    report
+}
+
+/// Writes a report, creating its directory if needed.
+pub(crate) fn write(path: &Path, report: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("cannot create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, report).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::executor::Outcome;
+    use crate::memory::Usage;
+    use crate::report::{json, junit, xml_escape};
+    use crate::verify::Status;
+    use serde_json::{Value, json};
+    use std::time::Duration;
+
+    fn outcome(
+        part: u8,
+        answer: i64,
+        expected: Option<i64>,
+        status: Result<Status, String>,
+    ) -> Outcome {
+        Outcome {
+            year: 2025,
+            day: 7,
+            part,
+            answer: Some(Answer::from(answer)),
+            expected: expected.map(Answer::from),
+            status,
+            time: Duration::from_millis(2),
+            timings: Some((Duration::from_nanos(1200), Duration::from_nanos(35))),
+            memory: None,
+            output: String::from("<oops>\n"),
+        }
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(
+            xml_escape("<a & 'b'>\u{1}"),
+            "&lt;a &amp; &apos;b&apos;&gt;"
+        );
+    }
+
+    #[test]
+    fn reports() {
+        let mut outcomes = vec![
+            outcome(1, 1587, Some(1587), Ok(Status::Pass)),
+            outcome(2, 12, Some(13), Ok(Status::Fail)),
+            outcome(1, 5, None, Ok(Status::Unknown)),
+            outcome(2, 0, None, Err(String::from("exited with \"1\""))),
+        ];
+
+        outcomes[1].memory = Some((
+            Usage {
+                peak: 64,
+                allocated: 96,
+                allocations: 3,
+            },
+            Usage::default(),
+        ));
+
+        let report: Value =
+            serde_json::from_str(&json(&outcomes, Duration::from_millis(10))).unwrap();
+        let results = &report["results"];
+        assert_eq!(
+            results[0],
+            json!({
+                "year": 2025, "day": 7, "part": 1, "answer": "1587", "expected": "1587",
+                "status": "pass", "error": null, "time_ns": 2000000, "parse_ns": 1200,
+                "solve_ns": 35, "parse_memory": null, "solve_memory": null
+            })
+        );
+        assert_eq!(
+            results[1]["parse_memory"],
+            json!({"peak_bytes": 64, "allocated_bytes": 96, "allocations": 3})
+        );
+        assert_eq!(results[1]["solve_memory"]["allocations"], 0);
+        assert_eq!(results[2]["expected"], Value::Null);
+        assert_eq!(results[2]["status"], "unknown");
+        assert_eq!(results[3]["error"], "exited with \"1\"");
+        assert_eq!(
+            report["summary"],
+            json!({"pass": 1, "fail": 1, "unknown": 1, "error": 1, "time_ns": 10000000})
+        );
+
+        let junit = junit(&outcomes, Duration::from_millis(10));
+        assert!(junit.contains(
+            "<testsuite name=\"2025\" tests=\"4\" failures=\"1\" errors=\"1\" skipped=\"1\""
+        ));
+        assert!(
+            junit
+                .contains("<testcase classname=\"2025.day07\" name=\"part 1\" time=\"0.002000\"/>")
+        );
+        assert!(junit.contains("<failure message=\"expected 13, got 12\"/>"));
+        assert!(junit.contains("<skipped message=\"no known answer, got 5\"/>"));
+        assert!(
+            junit.contains("<error message=\"exited with &quot;1&quot;\">&lt;oops&gt;\n</error>")
+        );
+    }
+}
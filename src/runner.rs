@@ -0,0 +1,218 @@
+use crate::answer::Answer;
+use crate::layout::ProfileArgs;
+use crate::memory::{self, Usage};
+use crate::sanity;
+use crate::solution::{Parser, Puzzle, Solution, registry};
+use crate::utils::files;
+use crate::utils::trace::{self, Filter};
+use crate::vault;
+use clap::Args;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[derive(Args, Debug)]
+pub(crate) struct RunArgs {
+    /// Year of the puzzle
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Day of the puzzle, required unless --all is given
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only run this part (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, `-` for the standard input, defaults to data/<year>/<profile>/dayNN.txt
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Run the example file of the profile, or else the example of the statement
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Run every registered day of the year
+    #[arg(short, long, conflicts_with = "day")]
+    all: bool,
+
+    /// Also print how long the parsing and each part took, in nanoseconds, and
+    /// with the `count-alloc` feature the memory they used
+    #[arg(long)]
+    timings: bool,
+
+    /// Trace output of the solutions, on stderr: `level`, `day=level` or `year/day=level`
+    /// directives separated by commas, levels being off, info, debug and trace
+    #[arg(short, long, env = "AOC_TRACE", default_value = "off")]
+    trace: Filter,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+pub(crate) fn run(args: &RunArgs) -> Result<(), String> {
+    let registry = registry();
+    trace::set_filter(args.trace.clone());
+
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => registry
+            .keys()
+            .filter(|(year, _)| *year == args.year)
+            .map(|(_, day)| *day)
+            .collect(),
+    };
+    if days.is_empty() {
+        return Err(format!("no solution registered for {}", args.year));
+    }
+
+    for day in days {
+        let Some(puzzle) = registry.get(&(args.year, day)) else {
+            return Err(format!(
+                "no solution registered for {} day {:02}",
+                args.year, day
+            ));
+        };
+        run_day(args, day, puzzle)?;
+    }
+    Ok(())
+}
+
+fn run_day(args: &RunArgs, day: u8, puzzle: &Puzzle) -> Result<(), String> {
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    // Both parts share the parsed input, unless the statement gives each part its own example.
+    let mut parsed: Option<(String, Box<dyn Solution>, Duration, Usage)> = None;
+    trace::enter(args.year, day);
+    let layout = args.profile.layout();
+    let examples = puzzle.examples();
+    // The input is read once for both parts: it may come from the standard input.
+    let input = match (&args.input, args.example) {
+        (_, true) => None,
+        (Some(path), false) => Some((path.clone(), read_input(path)?)),
+        (None, false) => {
+            let path = layout.input(args.year, day);
+            Some((path.clone(), read_input(&path)?))
+        }
+    };
+    for part in parts {
+        let example = &examples[part as usize - 1];
+        let example_file = layout.example(args.year, day, part);
+        // The example of the statement is trusted as it is.
+        let (path, input, statement) = match (&input, &example_file) {
+            (Some((path, input)), _) => (path.clone(), input.clone(), false),
+            (None, Some(path)) => (path.clone(), read_input(path)?, false),
+            (None, None) => (PathBuf::from("example"), example.input.clone(), true),
+        };
+
+        if parsed
+            .as_ref()
+            .is_none_or(|(previous, _, _, _)| *previous != input)
+        {
+            let checked = match statement {
+                true => input.clone(),
+                false => check_input(puzzle, &input, &path),
+            };
+            let start = Instant::now();
+            let parser = match args.example {
+                true => puzzle.parse_example,
+                false => puzzle.parse,
+            };
+            let (solution, usage) = memory::measure(|| parse(parser, &checked, &path));
+            parsed = Some((input, solution?, start.elapsed(), usage));
+        }
+        let (_, solution, parse_time, parse_usage) = parsed.as_ref().unwrap();
+
+        let start = Instant::now();
+        let (answer, solve_usage) = memory::measure(|| solution.solve(part));
+        let solve_time = start.elapsed();
+        // The answer of the statement only applies to its own example.
+        let expected = match (&example.answer, args.example && example_file.is_none()) {
+            (Some(expected), true) => format!(" (example answer {})", expected),
+            _ => String::new(),
+        };
+        println!(
+            "{}{}{}",
+            answer_prefix(args.year, day, part),
+            format_answer(answer.as_ref()),
+            expected
+        );
+        if args.timings {
+            println!(
+                "{}parse {} ns, solve {} ns",
+                timings_prefix(args.year, day, part),
+                parse_time.as_nanos(),
+                solve_time.as_nanos()
+            );
+            if memory::enabled() {
+                println!(
+                    "{}parse {}, solve {}",
+                    memory_prefix(args.year, day, part),
+                    parse_usage,
+                    solve_usage
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads a whole input file, or the standard input for `-`, decrypting it
+/// when it is only in the vault.
+pub(crate) fn read_input(path: &Path) -> Result<String, String> {
+    if vault::is_sealed(path) {
+        return vault::read(path);
+    }
+    files::open(path)
+        .and_then(files::read_string)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))
+}
+
+/// Checks the whitespace of an input against the shape of the puzzle,
+/// warning on stderr about what is off, and returns it normalised.
+pub(crate) fn check_input(puzzle: &Puzzle, input: &str, path: &Path) -> String {
+    let checked = sanity::check(input, puzzle.shape);
+    for issue in checked.issues.iter().filter(|issue| issue.warned()) {
+        let fixed = if issue.fixed() { " (normalised)" } else { "" };
+        eprintln!("warning: {}: {}{}", display_name(path), issue, fixed);
+    }
+    checked.input
+}
+
+/// The name of an input file in messages.
+pub(crate) fn display_name(path: &Path) -> String {
+    match path.to_str() {
+        Some(files::STDIN) => String::from("<stdin>"),
+        _ => path.display().to_string(),
+    }
+}
+
+/// Parses an input with the parser of a puzzle, a malformed one being
+/// reported with a diagnostic pointing at the problem.
+pub(crate) fn parse(parse: Parser, input: &str, path: &Path) -> Result<Box<dyn Solution>, String> {
+    parse(input).map_err(|e| e.diagnostic(&display_name(path)))
+}
+
+/// Prints a missing answer as `-`.
+pub(crate) fn format_answer(answer: Option<&Answer>) -> String {
+    answer.map_or_else(|| String::from("-"), Answer::to_string)
+}
+
+/// The beginning of the line printing the answer of a part.
+pub(crate) fn answer_prefix(year: u16, day: u8, part: u8) -> String {
+    format!("{} day {:02} part {}: ", year, day, part)
+}
+
+/// The beginning of the line printing the timings of a part, with `--timings`.
+pub(crate) fn timings_prefix(year: u16, day: u8, part: u8) -> String {
+    format!("{} day {:02} part {} timings: ", year, day, part)
+}
+
+/// The beginning of the line printing the memory used by a part, with
+/// `--timings` and the counting allocator.
+pub(crate) fn memory_prefix(year: u16, day: u8, part: u8) -> String {
+    format!("{} day {:02} part {} memory: ", year, day, part)
+}
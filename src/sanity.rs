@@ -0,0 +1,289 @@
+use crate::layout::ProfileArgs;
+use crate::runner::read_input;
+use crate::solution::{Shape, registry};
+use clap::Args;
+use std::fmt::{Display, Formatter};
+
+#[derive(Args, Debug)]
+pub(crate) struct CheckArgs {
+    /// Year of the puzzles
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Only check this day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// Something off in the whitespace of an input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Issue {
+    Empty,
+    /// Lines ending with `\r\n`, normalised to `\n`.
+    CrLf(Vec<usize>),
+    /// The last line has no `\n`, which is added.
+    MissingNewline,
+    /// Lines ending with spaces or tabs, when they are not significant.
+    TrailingSpaces(Vec<usize>),
+    /// Lines shorter than the widest one of a grid, and whether they are
+    /// padded with spaces.
+    Ragged {
+        width: usize,
+        lines: Vec<usize>,
+        padded: bool,
+    },
+}
+
+impl Issue {
+    /// Whether the issue is worth a warning before running a solution: a
+    /// missing final newline is common and harmless once added.
+    pub(crate) fn warned(&self) -> bool {
+        !matches!(self, Issue::MissingNewline)
+    }
+
+    /// Whether the input is normalised, rather than only warned about.
+    pub(crate) fn fixed(&self) -> bool {
+        match self {
+            Issue::CrLf(_) | Issue::MissingNewline => true,
+            Issue::Ragged { padded, .. } => *padded,
+            Issue::Empty | Issue::TrailingSpaces(_) => false,
+        }
+    }
+}
+
+/// Lists line numbers, the first few only.
+fn format_lines(lines: &[usize]) -> String {
+    const SHOWN: usize = 5;
+    let shown: Vec<String> = lines.iter().take(SHOWN).map(usize::to_string).collect();
+    let noun = if lines.len() == 1 { "line" } else { "lines" };
+    match lines.len().checked_sub(SHOWN) {
+        Some(more) if more > 0 => format!("{} {} and {} more", noun, shown.join(", "), more),
+        _ => format!("{} {}", noun, shown.join(", ")),
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Issue::Empty => write!(f, "the input is empty"),
+            Issue::CrLf(lines) => write!(f, "CRLF line endings on {}", format_lines(lines)),
+            Issue::MissingNewline => write!(f, "no newline at the end"),
+            Issue::TrailingSpaces(lines) => {
+                write!(f, "trailing whitespace on {}", format_lines(lines))
+            }
+            Issue::Ragged {
+                width,
+                lines,
+                padded: true,
+            } => write!(
+                f,
+                "{} shorter than the {} columns of the grid, likely stripped of their \
+                 trailing spaces",
+                format_lines(lines),
+                width
+            ),
+            Issue::Ragged { width, lines, .. } => write!(
+                f,
+                "{} shorter than the {} columns of the grid",
+                format_lines(lines),
+                width
+            ),
+        }
+    }
+}
+
+/// An input once normalised, and what was found in it.
+#[derive(Debug)]
+pub(crate) struct Checked {
+    pub(crate) input: String,
+    pub(crate) issues: Vec<Issue>,
+}
+
+/// Checks the whitespace of an input of the given shape: line endings are
+/// converted to `\n`, a final newline is added, and the lines of a grid whose
+/// spaces are significant are padded to a rectangle. What cannot be fixed
+/// safely is only reported.
+pub(crate) fn check(input: &str, shape: Shape) -> Checked {
+    let mut issues = Vec::new();
+    if input.trim().is_empty() {
+        issues.push(Issue::Empty);
+        return Checked {
+            input: input.to_string(),
+            issues,
+        };
+    }
+
+    let mut crlf = Vec::new();
+    let mut lines: Vec<&str> = Vec::new();
+    for (i, line) in input.split_terminator('\n').enumerate() {
+        match line.strip_suffix('\r') {
+            Some(line) => {
+                crlf.push(i + 1);
+                lines.push(line);
+            }
+            None => lines.push(line),
+        }
+    }
+    if !crlf.is_empty() {
+        issues.push(Issue::CrLf(crlf));
+    }
+    if !input.ends_with('\n') {
+        issues.push(Issue::MissingNewline);
+    }
+
+    if shape != Shape::SpacedGrid {
+        let trailing: Vec<usize> = (1..=lines.len())
+            .filter(|i| lines[i - 1].ends_with([' ', '\t']))
+            .collect();
+        if !trailing.is_empty() {
+            issues.push(Issue::TrailingSpaces(trailing));
+        }
+    }
+
+    // Blank lines are left alone: they end a grid rather than belong to it.
+    let width = lines.iter().map(|line| line.chars().count()).max().unwrap();
+    let short: Vec<usize> = (1..=lines.len())
+        .filter(|i| {
+            let count = lines[i - 1].chars().count();
+            count > 0 && count < width
+        })
+        .collect();
+    let padded = shape == Shape::SpacedGrid;
+    let mut output = String::with_capacity(input.len());
+    for line in &lines {
+        output.push_str(line);
+        if padded && !line.is_empty() {
+            let count = line.chars().count();
+            output.extend(std::iter::repeat_n(' ', width - count));
+        }
+        output.push('\n');
+    }
+    if shape != Shape::Text && !short.is_empty() {
+        issues.push(Issue::Ragged {
+            width,
+            lines: short,
+            padded,
+        });
+    }
+
+    Checked {
+        input: output,
+        issues,
+    }
+}
+
+/// Prints what is off in the inputs and example files of a year, without
+/// changing them.
+pub(crate) fn check_all(args: &CheckArgs) -> Result<(), String> {
+    let layout = args.profile.layout();
+    let mut checked = 0;
+    let mut warnings = 0;
+    for ((year, day), puzzle) in registry() {
+        if year != args.year || args.day.is_some_and(|d| d != day) {
+            continue;
+        }
+
+        let mut paths = vec![layout.input(year, day)];
+        for part in [1, 2] {
+            if let Some(example) = layout.example(year, day, part)
+                && !paths.contains(&example)
+            {
+                paths.push(example);
+            }
+        }
+        for path in paths.iter().filter(|path| crate::vault::exists(path)) {
+            let issues = check(&read_input(path)?, puzzle.shape).issues;
+            checked += 1;
+            warnings += issues.iter().filter(|issue| issue.warned()).count();
+            match issues.is_empty() {
+                true => println!("{}: ok", path.display()),
+                false => issues
+                    .iter()
+                    .for_each(|issue| println!("{}: {}", path.display(), issue)),
+            }
+        }
+    }
+    println!(
+        "{} file(s) checked, {} warning(s) before a run",
+        checked, warnings
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sanity::{Issue, check, format_lines};
+    use crate::solution::Shape;
+
+    #[test]
+    fn clean_input() {
+        let checked = check("L68\nL30\n", Shape::Text);
+        assert_eq!(checked.input, "L68\nL30\n");
+        assert!(checked.issues.is_empty());
+
+        assert_eq!(check(" \n", Shape::Text).issues, [Issue::Empty]);
+    }
+
+    #[test]
+    fn normalised() {
+        let checked = check("L68\r\nL30 \r\nR48", Shape::Text);
+
+        assert_eq!(checked.input, "L68\nL30 \nR48\n");
+        assert!(!checked.issues[1].warned());
+        assert!(checked.issues[0].fixed() && !checked.issues[2].fixed());
+        assert_eq!(
+            checked.issues,
+            [
+                Issue::CrLf(vec![1, 2]),
+                Issue::MissingNewline,
+                Issue::TrailingSpaces(vec![2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn grids() {
+        // The editor stripped the trailing spaces of the worksheet.
+        let worksheet = "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +\n";
+        let checked = check(worksheet, Shape::SpacedGrid);
+        assert_eq!(
+            checked.input,
+            "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n"
+        );
+        assert_eq!(
+            checked.issues,
+            [Issue::Ragged {
+                width: 15,
+                lines: vec![1, 2, 4],
+                padded: true,
+            }]
+        );
+
+        let checked = check("..@\n.@ \n@.\n", Shape::Grid);
+        assert_eq!(checked.input, "..@\n.@ \n@.\n");
+        assert_eq!(
+            checked.issues,
+            [
+                Issue::TrailingSpaces(vec![2]),
+                Issue::Ragged {
+                    width: 3,
+                    lines: vec![3],
+                    padded: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn line_lists() {
+        assert_eq!(format_lines(&[3]), "line 3");
+        assert_eq!(format_lines(&[1, 2, 3, 4, 5]), "lines 1, 2, 3, 4, 5");
+        assert_eq!(
+            format_lines(&[1, 2, 3, 4, 5, 6, 7]),
+            "lines 1, 2, 3, 4, 5 and 2 more"
+        );
+    }
+}
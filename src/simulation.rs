@@ -0,0 +1,82 @@
+use crate::utils::parse::ParseError;
+use crate::utils::point::{Map, Point2D};
+use std::collections::HashSet;
+
+/// A solution advancing over a grid one step at a time, which the step
+/// debugger can pause between steps.
+pub trait Simulation {
+    /// Advances one step, returning `false` once there is none left.
+    fn step(&mut self) -> bool;
+
+    /// The grid as it is now.
+    fn map(&self) -> &Map;
+
+    /// The cells the simulation is working on, highlighted when rendered.
+    fn frontier(&self) -> Vec<Point2D>;
+
+    /// Describes the state, such as the running totals.
+    fn status(&self) -> String;
+}
+
+/// Builds the simulation of a day from the raw puzzle input.
+pub type Simulator = fn(&str) -> Result<Box<dyn Simulation>, ParseError>;
+
+/// Renders the grid, the cells of the frontier in reverse video with `color`,
+/// or else replaced by `*`.
+pub fn render(simulation: &dyn Simulation, color: bool) -> String {
+    let map = simulation.map();
+    let frontier: HashSet<Point2D> = simulation.frontier().into_iter().collect();
+    let mut output = String::new();
+    for y in 0..map.y_max() {
+        for x in 0..map.x_max() {
+            let p = Point2D::new(x, y);
+            let c = map.get(&p).unwrap();
+            match (frontier.contains(&p), color) {
+                (false, _) => output.push(c),
+                (true, true) => output.push_str(&format!("\x1b[7m{}\x1b[0m", c)),
+                (true, false) => output.push('*'),
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::{Simulation, render};
+    use crate::solution::registry;
+
+    fn simulation(day: u8) -> Box<dyn Simulation> {
+        let puzzle = registry()[&(2025, day)];
+        let input = &puzzle.examples()[0].input;
+        (puzzle.simulate.unwrap())(input).unwrap()
+    }
+
+    #[test]
+    fn removal_waves() {
+        let mut simulation = simulation(4);
+        assert_eq!(simulation.frontier().len(), 13);
+        assert!(render(simulation.as_ref(), false).starts_with("..**.**@*.\n"));
+
+        let mut steps = 0;
+        while simulation.step() {
+            steps += 1;
+        }
+        assert_eq!(steps, 9);
+        assert!(simulation.frontier().is_empty());
+        assert_eq!(simulation.status(), "wave 9, 43 rolls removed");
+    }
+
+    #[test]
+    fn beams() {
+        let mut simulation = simulation(7);
+        assert_eq!(simulation.frontier().len(), 1);
+        assert!(simulation.step() && simulation.step());
+        assert_eq!(simulation.status(), "row 2, 2 beams, 1 splits, 2 timelines");
+        assert!(render(simulation.as_ref(), true).contains("\x1b[7m|\x1b[0m"));
+
+        while simulation.step() {}
+        assert!(simulation.status().ends_with("21 splits, 40 timelines"));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::answer::Answer;
+use crate::examples::{Example, examples};
+use crate::simulation::Simulator;
+use crate::utils::parse::ParseError;
+use crate::utils::random::Random;
+use std::collections::BTreeMap;
+
+/// A puzzle solution: the input is parsed once, then both parts are computed
+/// from the parsed representation. Malformed input is a `ParseError`.
+pub trait Solution {
+    fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> Option<Answer>;
+
+    fn part2(&self) -> Option<Answer>;
+
+    fn solve(&self, part: u8) -> Option<Answer> {
+        match part {
+            1 => self.part1(),
+            _ => self.part2(),
+        }
+    }
+}
+
+/// Builds a boxed solution from the raw puzzle input.
+pub type Parser = fn(&str) -> Result<Box<dyn Solution>, ParseError>;
+
+/// Writes a random input of about the given size, small sizes giving inputs
+/// small enough for a brute-force reference.
+pub type Generator = fn(&mut Random, usize) -> String;
+
+pub fn boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+/// How the lines of an input are laid out, which decides how its whitespace
+/// is checked before parsing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Shape {
+    /// Lines of any length.
+    #[default]
+    Text,
+    /// A grid whose lines all have the same length.
+    Grid,
+    /// A grid in which spaces are significant, including trailing ones.
+    SpacedGrid,
+}
+
+/// A registered puzzle: how to build its solution and the source file whose
+/// doc comment holds the puzzle statement.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub parse: Parser,
+    /// Builds the solution of the examples, which may use other parameters
+    /// than the real inputs.
+    pub parse_example: Parser,
+    pub source: &'static str,
+    pub shape: Shape,
+    /// Runs the input step by step in the debugger, for the days simulating a grid.
+    pub simulate: Option<Simulator>,
+    /// A slow but obviously correct solution, of which the parts with an
+    /// answer are compared against the actual solution on random inputs.
+    pub reference: Option<Parser>,
+    pub generate: Option<Generator>,
+    example_answers: [Option<&'static str>; 2],
+}
+
+impl Puzzle {
+    pub fn new<S: Solution + 'static>(source: &'static str) -> Self {
+        Puzzle {
+            parse: boxed::<S>,
+            parse_example: boxed::<S>,
+            source,
+            shape: Shape::Text,
+            simulate: None,
+            reference: None,
+            generate: None,
+            example_answers: [None; 2],
+        }
+    }
+
+    /// Declares how the lines of the input are laid out.
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Makes the input steppable in the debugger.
+    pub fn simulation(mut self, simulate: Simulator) -> Self {
+        self.simulate = Some(simulate);
+        self
+    }
+
+    /// Registers a brute-force solution to check the actual one against.
+    pub fn reference<S: Solution + 'static>(mut self) -> Self {
+        self.reference = Some(boxed::<S>);
+        self
+    }
+
+    /// Registers how to write random inputs.
+    pub fn generator(mut self, generate: Generator) -> Self {
+        self.generate = Some(generate);
+        self
+    }
+
+    /// Parses the examples differently from the real inputs.
+    pub fn example_parser(mut self, parse: Parser) -> Self {
+        self.parse_example = parse;
+        self
+    }
+
+    /// Overrides the example answer of a part read from the statement.
+    pub fn example_answer(mut self, part: u8, answer: &'static str) -> Self {
+        self.example_answers[part as usize - 1] = Some(answer);
+        self
+    }
+
+    /// The examples of the puzzle statement.
+    pub fn examples(&self) -> [Example; 2] {
+        let mut examples = examples(self.source);
+        for (example, answer) in examples.iter_mut().zip(self.example_answers) {
+            if let Some(answer) = answer {
+                example.answer = answer.parse().ok();
+            }
+        }
+        examples
+    }
+}
+
+/// Every registered puzzle, across all years.
+pub fn registry() -> BTreeMap<(u16, u8), Puzzle> {
+    let mut registry = BTreeMap::new();
+    registry.extend(crate::year2025::registry());
+    registry
+}
+
+/// Generates the tests of the registered days: each part is checked against
+/// the answers manifest and against the example of the statement.
+#[cfg(test)]
+macro_rules! puzzle_tests {
+    ($year:expr; $($module:ident: $day:expr),* $(,)?) => {
+        mod puzzles {
+            $(
+                mod $module {
+                    #[test]
+                    fn part_1() {
+                        crate::verify::assert_answer($year, $day, 1);
+                    }
+
+                    #[test]
+                    fn part_2() {
+                        crate::verify::assert_answer($year, $day, 2);
+                    }
+
+                    #[test]
+                    fn example_part_1() {
+                        crate::examples::assert_example($year, $day, 1);
+                    }
+
+                    #[test]
+                    fn example_part_2() {
+                        crate::examples::assert_example($year, $day, 2);
+                    }
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use puzzle_tests;
@@ -0,0 +1,522 @@
+use crate::answer::Answer;
+use crate::client::{Client, ClientArgs};
+use crate::layout::{Layout, ProfileArgs};
+use crate::manifest::Manifest;
+use crate::runner::{check_input, parse, read_input};
+use crate::solution::registry;
+use clap::Args;
+use num_bigint::BigInt;
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Args, Debug)]
+pub(crate) struct SubmitArgs {
+    /// Year of the puzzle
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part to submit (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to submit, defaults to the answer computed on the input of the profile
+    #[arg(short, long)]
+    answer: Option<String>,
+
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// What the puzzle site made of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling whether it is too high or too low.
+    Wrong,
+    /// The part was already solved: the answer was not checked.
+    AlreadySolved,
+    /// Submitted during a cooldown: the answer was not checked.
+    TooRecent,
+}
+
+impl Verdict {
+    fn keyword(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "right",
+            Verdict::TooHigh => "high",
+            Verdict::TooLow => "low",
+            Verdict::Wrong => "wrong",
+            Verdict::AlreadySolved => "solved",
+            Verdict::TooRecent => "early",
+        }
+    }
+
+    fn from_keyword(keyword: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::AlreadySolved,
+            Verdict::TooRecent,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.keyword() == keyword)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Verdict::Correct => "right answer",
+            Verdict::TooHigh => "wrong answer, too high",
+            Verdict::TooLow => "wrong answer, too low",
+            Verdict::Wrong => "wrong answer",
+            Verdict::AlreadySolved => "part already solved",
+            Verdict::TooRecent => "answer submitted too recently",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// The verdict read from the response page, with the cooldown it announces.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Response {
+    pub(crate) verdict: Verdict,
+    pub(crate) wait: Option<u64>,
+}
+
+/// Reads the verdict from the page returned after posting an answer.
+pub(crate) fn parse_response(page: &str) -> Result<Response, String> {
+    let text = match (page.find("<article>"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    let text = Regex::new(r"<[^>]*>").unwrap().replace_all(text, "");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::TooRecent
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else {
+        return Err(format!("unexpected response: {}", text));
+    };
+
+    // "You have 1m 5s left to wait" after a submission during a cooldown,
+    // "please wait one minute (or 5 minutes) before trying again" after a wrong answer.
+    let left = Regex::new(r"(?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let again = Regex::new(r"wait (one|\d+) minutes? before trying again").unwrap();
+    let wait = if let Some(captures) = left.captures(&text) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+        Some(minutes * 60 + seconds)
+    } else {
+        again.captures(&text).map(|captures| match &captures[1] {
+            "one" => 60,
+            minutes => minutes.parse::<u64>().unwrap() * 60,
+        })
+    };
+    Ok(Response { verdict, wait })
+}
+
+/// A submitted answer and its verdict.
+#[derive(Clone, Debug, PartialEq)]
+struct Guess {
+    day: u8,
+    part: u8,
+    verdict: Verdict,
+    answer: Answer,
+}
+
+/// Every answer submitted for a year, so that an answer known to be wrong is
+/// never submitted twice, and the cooldown announced by the site.
+///
+/// The log is a plain text file with one `<day> <part> <verdict> <answer>`
+/// entry per line, the verdict being `right`, `high`, `low` or `wrong`, and a
+/// `wait <unix time>` line when a cooldown is running.
+#[derive(Debug, Default)]
+pub(crate) struct Guesses {
+    guesses: Vec<Guess>,
+    wait_until: u64,
+}
+
+impl Guesses {
+    pub(crate) fn parse(content: &str) -> Result<Guesses, String> {
+        let mut guesses = Guesses::default();
+        for (n, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(time) = line.strip_prefix("wait ") {
+                guesses.wait_until = time
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("line {}: invalid time {}", n + 1, time))?;
+                continue;
+            }
+
+            let mut fields = line.splitn(4, char::is_whitespace);
+            let (Some(day), Some(part), Some(verdict), Some(answer)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!(
+                    "line {}: expected `<day> <part> <verdict> <answer>`",
+                    n + 1
+                ));
+            };
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| format!("line {}: invalid day {}", n + 1, day))?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(format!("line {}: invalid part {}", n + 1, part)),
+            };
+            let verdict = Verdict::from_keyword(verdict)
+                .ok_or_else(|| format!("line {}: invalid verdict {}", n + 1, verdict))?;
+            let Ok(answer) = answer.trim().parse::<Answer>();
+            guesses.guesses.push(Guess {
+                day,
+                part,
+                verdict,
+                answer,
+            });
+        }
+        Ok(guesses)
+    }
+
+    /// Loads the log, a missing file being an empty log.
+    pub(crate) fn load(path: &Path) -> Result<Guesses, String> {
+        if !path.exists() {
+            return Ok(Guesses::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        Guesses::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("cannot create {}: {}", parent.display(), e))?;
+        }
+        std::fs::write(path, self.to_string())
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+
+    /// Refuses an answer that cannot be right given the previous verdicts, or
+    /// submitted before the end of the cooldown.
+    pub(crate) fn check(&self, day: u8, part: u8, answer: &Answer, now: u64) -> Result<(), String> {
+        if now < self.wait_until {
+            return Err(format!(
+                "wait {}s before submitting again",
+                self.wait_until - now
+            ));
+        }
+
+        let value = answer.to_string().parse::<BigInt>().ok();
+        for guess in self
+            .guesses
+            .iter()
+            .filter(|guess| guess.day == day && guess.part == part)
+        {
+            if guess.verdict == Verdict::Correct {
+                return Err(format!("already solved, the answer was {}", guess.answer));
+            }
+            if guess.answer == *answer {
+                return Err(format!(
+                    "{} was already submitted: {}",
+                    answer, guess.verdict
+                ));
+            }
+
+            let bound = guess.answer.to_string().parse::<BigInt>().ok();
+            match (&value, &bound, guess.verdict) {
+                (Some(value), Some(bound), Verdict::TooHigh) if value >= bound => {
+                    return Err(format!(
+                        "{} is not below {}, already too high",
+                        answer, bound
+                    ));
+                }
+                (Some(value), Some(bound), Verdict::TooLow) if value <= bound => {
+                    return Err(format!(
+                        "{} is not above {}, already too low",
+                        answer, bound
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Remembers the verdict of a submitted answer and the cooldown it started.
+    pub(crate) fn record(
+        &mut self,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+        response: &Response,
+        now: u64,
+    ) {
+        if let Some(wait) = response.wait {
+            self.wait_until = now + wait;
+        }
+        // The answer was not checked: there is nothing to learn from it.
+        if matches!(
+            response.verdict,
+            Verdict::AlreadySolved | Verdict::TooRecent
+        ) {
+            return;
+        }
+        self.guesses.push(Guess {
+            day,
+            part,
+            verdict: response.verdict,
+            answer: answer.clone(),
+        });
+    }
+}
+
+impl Display for Guesses {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day part verdict answer")?;
+        for guess in &self.guesses {
+            writeln!(
+                f,
+                "{} {} {} {}",
+                guess.day,
+                guess.part,
+                guess.verdict.keyword(),
+                guess.answer
+            )?;
+        }
+        if self.wait_until > 0 {
+            writeln!(f, "wait {}", self.wait_until)?;
+        }
+        Ok(())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Submits an answer unless the log already rules it out, then records the verdict.
+pub(crate) fn submit_answer(
+    client: &Client,
+    guesses: &mut Guesses,
+    (year, day, part): (u16, u8, u8),
+    answer: &Answer,
+    now: u64,
+) -> Result<Response, String> {
+    guesses.check(day, part, answer, now)?;
+    let page = client.answer(year, day, part, &answer.to_string())?;
+    let response = parse_response(&page)?;
+    guesses.record(day, part, answer, &response, now);
+    Ok(response)
+}
+
+pub(crate) fn submit(args: &SubmitArgs) -> Result<(), String> {
+    let layout = args.profile.layout();
+    let answer = match &args.answer {
+        Some(answer) => {
+            let Ok(answer) = answer.parse::<Answer>();
+            answer
+        }
+        None => solve(&layout, args.year, args.day, args.part)?,
+    };
+
+    let path = layout.guesses(args.year);
+    let mut guesses = Guesses::load(&path)?;
+    guesses.check(args.day, args.part, &answer, now())?;
+
+    let client = Client::from_args(&args.client)?;
+    let result = submit_answer(
+        &client,
+        &mut guesses,
+        (args.year, args.day, args.part),
+        &answer,
+        now(),
+    );
+    guesses.save(&path)?;
+    let response = result?;
+
+    let wait = match response.wait {
+        Some(wait) => format!(" (wait {}s)", wait),
+        None => String::new(),
+    };
+    println!(
+        "{}{}: {}{}",
+        crate::runner::answer_prefix(args.year, args.day, args.part),
+        answer,
+        response.verdict,
+        wait
+    );
+    if response.verdict == Verdict::Correct {
+        record_answer(&layout, args.year, args.day, args.part, &answer)?;
+    }
+    Ok(())
+}
+
+/// Computes the answer of a part on its default input.
+fn solve(layout: &Layout, year: u16, day: u8, part: u8) -> Result<Answer, String> {
+    let registry = registry();
+    let Some(puzzle) = registry.get(&(year, day)) else {
+        return Err(format!(
+            "no solution registered for {} day {:02}",
+            year, day
+        ));
+    };
+    let path = layout.input(year, day);
+    let input = check_input(puzzle, &read_input(&path)?, &path);
+    parse(puzzle.parse, &input, &path)?
+        .solve(part)
+        .ok_or_else(|| format!("{} day {:02} part {} has no answer", year, day, part))
+}
+
+/// Adds a right answer to the answers manifest, unless it is already there.
+fn record_answer(
+    layout: &Layout,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+) -> Result<(), String> {
+    let path = layout.answers(year);
+    if Manifest::load(&path)?.get(day, part).is_some() {
+        return Ok(());
+    }
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{} {} {}", day, part, answer))
+        .map_err(|e| format!("cannot write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::client::{Client, stand_in};
+    use crate::submit::{Guesses, Response, Verdict, parse_response, submit_answer};
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+        high. If you're stuck, make sure you're using the full input data. Please wait one \
+        minute before trying again. [<a href=\"/2025/day/1\">Return to Day 1</a>]</p></article></main>";
+    const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too \
+        low. Please wait 5 minutes before trying again.</p></article></main>";
+    const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to \
+        wait after submitting an answer before trying again.  You have 1m 5s left to wait. \
+        </p></article></main>";
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are one gold star \
+        closer to decorating the North Pole.</p></article></main>";
+
+    #[test]
+    fn responses() {
+        let response = |verdict, wait| Response { verdict, wait };
+
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            Ok(response(Verdict::TooHigh, Some(60)))
+        );
+        assert_eq!(
+            parse_response(TOO_LOW),
+            Ok(response(Verdict::TooLow, Some(300)))
+        );
+        assert_eq!(
+            parse_response(TOO_RECENT),
+            Ok(response(Verdict::TooRecent, Some(65)))
+        );
+        assert_eq!(
+            parse_response(CORRECT),
+            Ok(response(Verdict::Correct, None))
+        );
+        assert!(parse_response("<article><p>Puzzle inputs differ by user.</p></article>").is_err());
+    }
+
+    #[test]
+    fn guesses() {
+        let guesses = Guesses::parse(
+            "# day part verdict answer\n1 1 high 5000\n1 1 low 100\n1 2 wrong ABC\nwait 1000\n",
+        )
+        .unwrap();
+
+        assert!(guesses.check(1, 1, &Answer::from(1031), 999).is_err());
+        assert!(guesses.check(1, 1, &Answer::from(1031), 1000).is_ok());
+        assert!(guesses.check(1, 1, &Answer::from(5000), 1000).is_err());
+        assert!(guesses.check(1, 1, &Answer::from(6000), 1000).is_err());
+        assert!(guesses.check(1, 1, &Answer::from(100), 1000).is_err());
+        assert!(guesses.check(1, 2, &Answer::from("ABC"), 1000).is_err());
+        assert!(guesses.check(1, 2, &Answer::from(6000), 1000).is_ok());
+        assert_eq!(
+            Guesses::parse(&guesses.to_string()).unwrap().to_string(),
+            guesses.to_string()
+        );
+
+        assert!(Guesses::parse("1 1 maybe 5").is_err());
+        assert!(Guesses::parse("wait soon").is_err());
+    }
+
+    #[test]
+    fn submit() {
+        let (url, server) = stand_in::serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(&url, "53c2e7");
+        let mut guesses = Guesses::default();
+
+        let response = submit_answer(&client, &mut guesses, (2025, 1, 2), &Answer::from(9000), 0);
+        assert_eq!(response.map(|r| r.verdict), Ok(Verdict::TooHigh));
+        // Refused locally: the cooldown is running, then the value is known to be too high.
+        assert!(
+            submit_answer(&client, &mut guesses, (2025, 1, 2), &Answer::from(5831), 30).is_err()
+        );
+        assert!(
+            submit_answer(&client, &mut guesses, (2025, 1, 2), &Answer::from(9001), 60).is_err()
+        );
+        let response = submit_answer(&client, &mut guesses, (2025, 1, 2), &Answer::from(5831), 60);
+        assert_eq!(response.map(|r| r.verdict), Ok(Verdict::Correct));
+        assert!(
+            submit_answer(
+                &client,
+                &mut guesses,
+                (2025, 1, 2),
+                &Answer::from(5832),
+                120
+            )
+            .is_err()
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=9000"));
+        assert!(requests[1].ends_with("level=2&answer=5831"));
+    }
+}
@@ -1,9 +1,81 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
 use std::path::Path;
 
-pub fn read_lines(path: &Path) -> Vec<String> {
-    std::fs::read_to_string(path)
-        .unwrap() // panic on possible file-reading errors
-        .lines() // split the string into an iterator of string slices
-        .map(String::from) // make each slice into a string
-        .collect() // gather them together into a vector
+/// Path standing for the standard input.
+pub const STDIN: &str = "-";
+
+/// Opens a file for buffered reading, or the standard input for `-`.
+pub fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path == Path::new(STDIN) {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    Ok(Box::new(BufReader::new(File::open(path)?)))
+}
+
+/// Yields the lines of a reader one at a time, without their line ending.
+/// A failed read is yielded as an error instead of a line.
+pub fn lines<R: BufRead>(reader: R) -> impl Iterator<Item = io::Result<String>> {
+    reader.lines()
+}
+
+/// Reads a whole input at once, for parsers working on the raw string.
+pub fn read_string<R: Read>(mut reader: R) -> io::Result<String> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+/// Reads a whole input at once, for parsers working on bytes.
+pub fn read_bytes<R: Read>(mut reader: R) -> io::Result<Vec<u8>> {
+    let mut content = Vec::new();
+    reader.read_to_end(&mut content)?;
+    Ok(content)
+}
+
+pub fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    lines(open(path)?).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::files::{lines, read_bytes, read_lines, read_string};
+    use std::io::{self, BufReader, Cursor, Read};
+    use std::path::Path;
+
+    /// A reader failing after its first bytes.
+    struct Failing(Cursor<&'static str>);
+
+    impl Read for Failing {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.read(buf)? {
+                0 => Err(io::Error::other("disconnected")),
+                n => Ok(n),
+            }
+        }
+    }
+
+    #[test]
+    fn read() {
+        let mut lines = lines(Cursor::new("L68\r\nL30\n\nR48"));
+        assert_eq!(lines.next().unwrap().unwrap(), "L68");
+        assert_eq!(lines.next().unwrap().unwrap(), "L30");
+        assert_eq!(lines.next().unwrap().unwrap(), "");
+        assert_eq!(lines.next().unwrap().unwrap(), "R48");
+        assert!(lines.next().is_none());
+
+        assert_eq!(read_string(Cursor::new("L68\n")).unwrap(), "L68\n");
+        assert_eq!(read_bytes(Cursor::new("L68\n")).unwrap(), b"L68\n");
+    }
+
+    #[test]
+    fn errors() {
+        let mut lines = lines(BufReader::new(Failing(Cursor::new("L68\nL3"))));
+        assert_eq!(lines.next().unwrap().unwrap(), "L68");
+        assert!(lines.next().unwrap().is_err());
+
+        assert!(read_string(Failing(Cursor::new("L68\n"))).is_err());
+        assert!(read_string(Cursor::new(b"\xff\xfe")).is_err());
+        assert!(read_lines(Path::new("data/missing.txt")).is_err());
+    }
 }
@@ -1,2 +1,7 @@
+//! Shared helpers for the puzzles, also available to other tools.
+
 pub mod files;
+pub mod parse;
 pub mod point;
+pub mod random;
+pub mod trace;
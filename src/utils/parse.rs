@@ -0,0 +1,176 @@
+use regex::{Captures, Regex};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A malformed input: where the problem is, the offending text and what was
+/// wrong with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, from 1.
+    pub line: usize,
+    /// Column of the offending text in the line, in characters from 1.
+    pub column: usize,
+    /// The offending text, empty when something is missing.
+    pub text: String,
+    /// The whole line, to show the offending text in context.
+    pub source: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about something missing after the last line of the input.
+    pub fn missing(input: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            line: input.lines().count() + 1,
+            column: 1,
+            text: String::new(),
+            source: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Shows the error as `name:line:column: message`, followed by the line
+    /// with the offending text underlined by carets.
+    pub fn diagnostic(&self, name: &str) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        format!(
+            "{}:{}:{}: {}\n{} |\n{} | {}\n{} | {}{}",
+            name,
+            self.line,
+            self.column,
+            self.message,
+            gutter,
+            number,
+            self.source,
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.text.chars().count().max(1))
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of the input along with its number, to report errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// Line number, from 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// The numbered lines of an input.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// An error about `part`, a slice of the line: the whole line is blamed
+    /// when `part` is not one.
+    pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let slice = offset
+            .checked_add(part.len())
+            .and_then(|end| self.text.get(offset..end));
+        let (offset, part) = match slice {
+            Some(_) => (offset, part),
+            None => (0, self.text),
+        };
+        ParseError {
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            text: part.to_string(),
+            source: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Parses `part`, a slice of the line, as a `T`.
+    pub fn parse<T: FromStr>(&self, part: &str) -> Result<T, ParseError> {
+        part.parse().map_err(|_| {
+            let expected = std::any::type_name::<T>().rsplit("::").next().unwrap();
+            self.error(part, format!("expected {}, found `{}`", expected, part))
+        })
+    }
+
+    /// Matches the line against `regex`, an `expected` description of it
+    /// being reported otherwise.
+    pub fn captures(&self, regex: &Regex, expected: &str) -> Result<Captures<'a>, ParseError> {
+        regex
+            .captures(self.text)
+            .ok_or_else(|| self.error(self.text, format!("expected {}", expected)))
+    }
+
+    /// Checks that every character of the line is one of `expected`.
+    pub fn expect_chars(&self, expected: &str) -> Result<(), ParseError> {
+        match self
+            .text
+            .char_indices()
+            .find(|(_, c)| !expected.contains(*c))
+        {
+            Some((i, c)) => Err(self.error(
+                &self.text[i..i + c.len_utf8()],
+                format!("expected one of `{}`, found `{}`", expected, c),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Splits `part`, a slice of the line, around the first `delimiter`.
+    pub fn split_once(
+        &self,
+        part: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error(part, format!("expected `{}`", delimiter)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse::{ParseError, lines};
+
+    #[test]
+    fn errors() {
+        let input = "162,817,812\n57,6x8,66\n";
+        let line = lines(input).nth(1).unwrap();
+        let field = line.text.split(',').nth(1).unwrap();
+
+        let error = line.parse::<i64>(field).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "6x8");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected i64, found `6x8`"
+        );
+        assert_eq!(
+            error.diagnostic("day08.txt"),
+            "day08.txt:2:4: expected i64, found `6x8`\n  |\n2 | 57,6x8,66\n  |    ^^^"
+        );
+
+        // Text outside of the line blames the whole line.
+        let error = line.error("elsewhere", "unexpected line");
+        assert_eq!((error.column, error.text.as_str()), (1, "57,6x8,66"));
+
+        assert!(line.split_once(line.text, ":").is_err());
+        assert!(line.expect_chars("0123456789,").is_err());
+        assert_eq!(line.expect_chars("0123456789,x"), Ok(()));
+        assert_eq!(line.split_once(field, "x"), Ok(("6", "8")));
+        assert_eq!(ParseError::missing(input, "no region").line, 3);
+    }
+}
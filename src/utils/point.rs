@@ -1,24 +1,25 @@
 #[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
-pub(crate) struct Point2D {
+pub struct Point2D {
     x: i64,
     y: i64,
 }
 
 #[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
-pub(crate) struct Point3D {
+pub struct Point3D {
     x: i64,
     y: i64,
     z: i64,
 }
 
-pub(crate) struct Map {
+#[derive(Clone)]
+pub struct Map {
     map: Vec<Vec<char>>,
     x_max: i64,
     y_max: i64,
 }
 
 #[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
-pub(crate) struct Rectangle {
+pub struct Rectangle {
     x_min: i64,
     x_max: i64,
     y_min: i64,
@@ -26,7 +27,7 @@ pub(crate) struct Rectangle {
 }
 
 impl Rectangle {
-    pub(crate) fn new(x_min: i64, x_max: i64, y_min: i64, y_max: i64) -> Self {
+    pub fn new(x_min: i64, x_max: i64, y_min: i64, y_max: i64) -> Self {
         Rectangle {
             x_min,
             x_max,
@@ -35,7 +36,7 @@ impl Rectangle {
         }
     }
 
-    pub(crate) fn from(p1: &Point2D, p2: &Point2D) -> Rectangle {
+    pub fn from(p1: &Point2D, p2: &Point2D) -> Rectangle {
         let x_min = std::cmp::min(p1.x, p2.x);
         let x_max = std::cmp::max(p1.x, p2.x);
         let y_min = std::cmp::min(p1.y, p2.y);
@@ -43,14 +44,14 @@ impl Rectangle {
         Rectangle::new(x_min, x_max, y_min, y_max)
     }
 
-    pub(crate) fn overlaps_with(self, other: &Rectangle) -> bool {
+    pub fn overlaps_with(self, other: &Rectangle) -> bool {
         self.x_max > other.x_min
             && other.x_max > self.x_min
             && self.y_max > other.y_min
             && other.y_max > self.y_min
     }
 
-    pub(crate) fn area(self) -> i64 {
+    pub fn area(self) -> i64 {
         let dx = (self.x_min - self.x_max).abs();
         let dy = (self.y_min - self.y_max).abs();
         (dx + 1) * (dy + 1)
@@ -75,22 +76,22 @@ impl Point2D {
     }
 
     pub fn neighbors(&self) -> Vec<Point2D> {
-        let mut neighbors = vec![];
-        neighbors.push(Point2D::new(self.x - 1, self.y - 1));
-        neighbors.push(Point2D::new(self.x, self.y - 1));
-        neighbors.push(Point2D::new(self.x + 1, self.y - 1));
-        neighbors.push(Point2D::new(self.x + 1, self.y));
-        neighbors.push(Point2D::new(self.x - 1, self.y));
-        neighbors.push(Point2D::new(self.x - 1, self.y + 1));
-        neighbors.push(Point2D::new(self.x, self.y + 1));
-        neighbors.push(Point2D::new(self.x + 1, self.y + 1));
-        neighbors
+        vec![
+            Point2D::new(self.x - 1, self.y - 1),
+            Point2D::new(self.x, self.y - 1),
+            Point2D::new(self.x + 1, self.y - 1),
+            Point2D::new(self.x + 1, self.y),
+            Point2D::new(self.x - 1, self.y),
+            Point2D::new(self.x - 1, self.y + 1),
+            Point2D::new(self.x, self.y + 1),
+            Point2D::new(self.x + 1, self.y + 1),
+        ]
     }
 
     pub fn valid(&self, x_max: i64, y_max: i64) -> bool {
         self.x < x_max && self.y < y_max && self.x >= 0 && self.y >= 0
     }
-    
+
     pub fn move_xy(&self, x: i64, y: i64) -> Point2D {
         Point2D::new(self.x + x, self.y + y)
     }
@@ -123,9 +124,10 @@ impl Point3D {
 }
 
 impl Map {
+    /// A grid as wide as its longest row, empty without any row.
     pub fn new(map: Vec<Vec<char>>) -> Self {
         let y_max = map.len() as i64;
-        let x_max = map.iter().map(|row| row.len()).max().unwrap() as i64;
+        let x_max = map.iter().map(|row| row.len()).max().unwrap_or(0) as i64;
         Map { map, y_max, x_max }
     }
 
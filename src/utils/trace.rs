@@ -0,0 +1,176 @@
+use std::cell::Cell;
+use std::fmt::{Arguments, Display, Formatter};
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// How much the solutions tell about what they are doing, from nothing to
+/// every step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    #[default]
+    Off,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Level::Off),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "invalid level `{}`, expected off, info, debug or trace",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The level of each day: comma-separated `level`, `day=level` or
+/// `year/day=level` directives, the most specific one winning.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Filter {
+    default: Level,
+    days: Vec<(Option<u16>, u8, Level)>,
+}
+
+impl Filter {
+    pub fn level(&self, year: u16, day: u8) -> Level {
+        let find = |with_year: bool| {
+            self.days
+                .iter()
+                .rev()
+                .find(|(y, d, _)| {
+                    *d == day && y.is_some() == with_year && y.is_none_or(|y| y == year)
+                })
+                .map(|(_, _, level)| *level)
+        };
+        find(true).or_else(|| find(false)).unwrap_or(self.default)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let Some((target, level)) = directive.split_once('=') else {
+                filter.default = directive.parse()?;
+                continue;
+            };
+            let (year, day) = match target.split_once('/') {
+                Some((year, day)) => (Some(year), day),
+                None => (None, target),
+            };
+            let year = year
+                .map(|year| year.parse::<u16>())
+                .transpose()
+                .map_err(|_| format!("invalid year in `{}`", directive))?;
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| format!("invalid day in `{}`", directive))?;
+            filter.days.push((year, day, level.parse()?));
+        }
+        Ok(filter)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter {
+    default: Level::Off,
+    days: Vec::new(),
+});
+
+thread_local! {
+    /// The day run by this thread and its level, so checking the level of a
+    /// message does not lock the filter.
+    static DAY: Cell<Option<(u16, u8, Level)>> = const { Cell::new(None) };
+}
+
+/// Sets the level of each day; everything is silent until then.
+pub fn set_filter(filter: Filter) {
+    *FILTER.write().unwrap() = filter;
+    if let Some((year, day, _)) = DAY.get() {
+        enter(year, day);
+    }
+}
+
+/// Marks the start of a day on this thread: its messages follow its level.
+pub fn enter(year: u16, day: u8) {
+    let level = FILTER.read().unwrap().level(year, day);
+    DAY.set(Some((year, day, level)));
+}
+
+pub fn enabled(level: Level) -> bool {
+    DAY.get()
+        .is_some_and(|(_, _, day_level)| level <= day_level)
+}
+
+/// Writes a message to the standard error, keeping the standard output for
+/// the answers.
+pub fn log(level: Level, message: Arguments) {
+    if let Some((year, day, _)) = DAY.get() {
+        eprintln!("[{} day {:02} {}] {}", year, day, level, message);
+    }
+}
+
+/// Logs a message at a level, its arguments being only evaluated when the
+/// level of the current day allows it.
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::utils::trace::enabled($level) {
+            $crate::utils::trace::log($level, format_args!($($arg)*));
+        }
+    };
+}
+
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::utils::trace::log_at!($crate::utils::trace::Level::Info, $($arg)*) };
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::utils::trace::log_at!($crate::utils::trace::Level::Debug, $($arg)*) };
+}
+
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::utils::trace::log_at!($crate::utils::trace::Level::Trace, $($arg)*) };
+}
+
+pub(crate) use {debug, info, log_at, trace};
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::trace::{Filter, Level};
+
+    #[test]
+    fn filters() {
+        let filter: Filter = "info, 8=trace, 2024/8=off, 2025/10=debug".parse().unwrap();
+
+        assert_eq!(filter.level(2025, 1), Level::Info);
+        assert_eq!(filter.level(2025, 8), Level::Trace);
+        assert_eq!(filter.level(2024, 8), Level::Off);
+        assert_eq!(filter.level(2025, 10), Level::Debug);
+        assert_eq!(filter.level(2024, 10), Level::Info);
+        assert_eq!(Filter::default().level(2025, 1), Level::Off);
+
+        assert!("loud".parse::<Filter>().is_err());
+        assert!("day8=trace".parse::<Filter>().is_err());
+    }
+}
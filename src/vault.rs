@@ -0,0 +1,361 @@
+use crate::layout::DATA;
+use chacha20poly1305::aead::{Aead, Generate, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use clap::{Args, Subcommand};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// File holding the key of the vault, which must never be committed.
+pub(crate) const KEY_FILE: &str = ".vault-key";
+
+/// Environment variable giving another path to the key file.
+pub(crate) const KEY_ENV: &str = "AOC_VAULT_KEY";
+
+/// Extension added to the name of an encrypted file: `day01.txt.enc`.
+pub(crate) const EXTENSION: &str = "enc";
+
+/// Start of every encrypted file, followed by the nonce and the ciphertext.
+const MAGIC: &[u8] = b"aocvault1\n";
+
+const NONCE_SIZE: usize = 24;
+
+#[derive(Args, Debug)]
+pub(crate) struct VaultArgs {
+    #[command(subcommand)]
+    action: VaultAction,
+
+    /// Key file, defaults to $AOC_VAULT_KEY or else .vault-key
+    #[arg(short, long, global = true)]
+    key: Option<PathBuf>,
+
+    /// Directory holding the files of every year and profile
+    #[arg(long, global = true, default_value = DATA)]
+    data: PathBuf,
+}
+
+#[derive(Subcommand, Debug)]
+enum VaultAction {
+    /// Create a new key file
+    Init,
+    /// Encrypt the inputs and examples, removing the plain files
+    Encrypt {
+        /// Keep the plain files next to the encrypted ones
+        #[arg(long)]
+        keep: bool,
+    },
+    /// Decrypt the inputs and examples next to the encrypted files
+    Decrypt,
+}
+
+/// Encrypts and decrypts files with the key of the vault.
+pub(crate) struct Vault {
+    cipher: XChaCha20Poly1305,
+}
+
+impl Vault {
+    /// Creates a vault with a new random key, returned in hexadecimal.
+    pub(crate) fn generate() -> (Vault, String) {
+        let key = Key::generate();
+        let hex = key.iter().map(|b| format!("{:02x}", b)).collect();
+        (
+            Vault {
+                cipher: XChaCha20Poly1305::new(&key),
+            },
+            hex,
+        )
+    }
+
+    /// Reads a key written in hexadecimal.
+    pub(crate) fn from_hex(hex: &str) -> Result<Vault, String> {
+        let hex = hex.trim();
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| {
+                hex.get(i..i + 2)
+                    .and_then(|b| u8::from_str_radix(b, 16).ok())
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| String::from("the key is not hexadecimal"))?;
+        let cipher = XChaCha20Poly1305::new_from_slice(&bytes)
+            .map_err(|_| format!("the key has {} bytes instead of 32", bytes.len()))?;
+        Ok(Vault { cipher })
+    }
+
+    /// Loads the key file, explaining what to do when it is missing.
+    pub(crate) fn load(path: &Path) -> Result<Vault, String> {
+        let hex = std::fs::read_to_string(path).map_err(|e| {
+            format!(
+                "cannot read the vault key {}: {}; ask a teammate for it, \
+                 create one with `vault init`, or set {} to its path",
+                path.display(),
+                e,
+                KEY_ENV
+            )
+        })?;
+        Vault::from_hex(&hex).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub(crate) fn encrypt(&self, plain: &[u8]) -> Vec<u8> {
+        let nonce = XNonce::generate();
+        let ciphertext = self.cipher.encrypt(&nonce, plain).unwrap();
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub(crate) fn decrypt(&self, sealed: &[u8]) -> Result<Vec<u8>, String> {
+        let sealed = sealed
+            .strip_prefix(MAGIC)
+            .filter(|sealed| sealed.len() >= NONCE_SIZE)
+            .ok_or_else(|| String::from("not an encrypted file"))?;
+        let (nonce, ciphertext) = sealed.split_at(NONCE_SIZE);
+        let nonce = XNonce::try_from(nonce).unwrap();
+        self.cipher
+            .decrypt(&nonce, ciphertext)
+            .map_err(|_| String::from("cannot decrypt, the key is wrong or the file is damaged"))
+    }
+}
+
+/// The key file: the one given, or `$AOC_VAULT_KEY`, or `.vault-key`.
+pub(crate) fn key_path(key: Option<&Path>) -> PathBuf {
+    match (key, std::env::var_os(KEY_ENV)) {
+        (Some(key), _) => key.to_path_buf(),
+        (None, Some(key)) => PathBuf::from(key),
+        (None, None) => PathBuf::from(KEY_FILE),
+    }
+}
+
+/// The encrypted file standing for a plain one.
+pub(crate) fn sealed(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Whether a file is there, in plain or encrypted.
+pub(crate) fn exists(path: &Path) -> bool {
+    path.exists() || sealed(path).exists()
+}
+
+/// Whether a file is only there encrypted.
+pub(crate) fn is_sealed(path: &Path) -> bool {
+    !path.exists() && sealed(path).exists()
+}
+
+/// Reads a file that is only there encrypted, with the default key file.
+pub(crate) fn read(path: &Path) -> Result<String, String> {
+    let sealed = sealed(path);
+    let content =
+        std::fs::read(&sealed).map_err(|e| format!("cannot read {}: {}", sealed.display(), e))?;
+    let vault = Vault::load(&key_path(None))
+        .map_err(|e| format!("{} is encrypted, but {}", sealed.display(), e))?;
+    let plain = vault
+        .decrypt(&content)
+        .map_err(|e| format!("{}: {}", sealed.display(), e))?;
+    String::from_utf8(plain).map_err(|e| format!("{}: {}", sealed.display(), e))
+}
+
+/// Whether a file is an input or an example, which are kept encrypted.
+fn is_protected(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("day") && name.ends_with(".txt"))
+}
+
+/// Every file below a directory, in a stable order.
+fn walk(directory: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    let entries = std::fs::read_dir(directory)
+        .map_err(|e| format!("cannot read {}: {}", directory.display(), e))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("cannot read {}: {}", directory.display(), e))?
+            .path();
+        if path.is_dir() {
+            files.extend(walk(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Encrypts every input and example below a directory, returning the files
+/// written. An encrypted file already holding the same content is kept as is,
+/// so that the repository only changes with the inputs.
+pub(crate) fn encrypt_all(
+    vault: &Vault,
+    directory: &Path,
+    keep: bool,
+) -> Result<Vec<PathBuf>, String> {
+    let mut written = Vec::new();
+    for path in walk(directory)?
+        .into_iter()
+        .filter(|path| is_protected(path))
+    {
+        let plain =
+            std::fs::read(&path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let sealed = sealed(&path);
+        let unchanged = std::fs::read(&sealed)
+            .ok()
+            .and_then(|content| vault.decrypt(&content).ok())
+            .is_some_and(|content| content == plain);
+        if !unchanged {
+            std::fs::write(&sealed, vault.encrypt(&plain))
+                .map_err(|e| format!("cannot write {}: {}", sealed.display(), e))?;
+            written.push(sealed);
+        }
+        if !keep {
+            std::fs::remove_file(&path)
+                .map_err(|e| format!("cannot remove {}: {}", path.display(), e))?;
+        }
+    }
+    Ok(written)
+}
+
+/// Decrypts every encrypted file below a directory next to it, returning the
+/// files written. A plain file that differs from the encrypted one is left
+/// alone, being likely more recent.
+pub(crate) fn decrypt_all(vault: &Vault, directory: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut written = Vec::new();
+    for sealed in walk(directory)? {
+        if sealed
+            .extension()
+            .is_none_or(|extension| extension != EXTENSION)
+        {
+            continue;
+        }
+        let content = std::fs::read(&sealed)
+            .map_err(|e| format!("cannot read {}: {}", sealed.display(), e))?;
+        let plain = vault
+            .decrypt(&content)
+            .map_err(|e| format!("{}: {}", sealed.display(), e))?;
+        let path = sealed.with_extension("");
+        match std::fs::read(&path) {
+            Ok(existing) if existing == plain => {}
+            Ok(_) => eprintln!(
+                "warning: {} differs from {}, kept",
+                path.display(),
+                sealed.display()
+            ),
+            Err(_) => {
+                std::fs::write(&path, plain)
+                    .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+                written.push(path);
+            }
+        }
+    }
+    Ok(written)
+}
+
+/// Writes a new key file, only readable by its owner on Unix.
+fn write_key(path: &Path, hex: &str) -> Result<(), String> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options
+        .open(path)
+        .map_err(|e| format!("cannot create {}: {}", path.display(), e))?;
+    writeln!(file, "{}", hex).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+}
+
+pub(crate) fn vault(args: &VaultArgs) -> Result<(), String> {
+    let key = key_path(args.key.as_deref());
+    match args.action {
+        VaultAction::Init => {
+            if key.exists() {
+                return Err(format!("{} already exists", key.display()));
+            }
+            let (_, hex) = Vault::generate();
+            write_key(&key, &hex)?;
+            println!("created {}, keep it out of the repository", key.display());
+        }
+        VaultAction::Encrypt { keep } => {
+            let written = encrypt_all(&Vault::load(&key)?, &args.data, keep)?;
+            written
+                .iter()
+                .for_each(|path| println!("encrypted {}", path.display()));
+        }
+        VaultAction::Decrypt => {
+            let written = decrypt_all(&Vault::load(&key)?, &args.data)?;
+            written
+                .iter()
+                .for_each(|path| println!("decrypted {}", path.display()));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vault::{Vault, decrypt_all, encrypt_all, sealed, write_key};
+    use std::path::Path;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn keys() {
+        let vault = Vault::from_hex(KEY).unwrap();
+        let sealed = vault.encrypt(b"L68\nL30\n");
+        assert_ne!(vault.encrypt(b"L68\nL30\n"), sealed);
+        assert_eq!(vault.decrypt(&sealed).unwrap(), b"L68\nL30\n");
+
+        let (other, hex) = Vault::generate();
+        assert_eq!(hex.len(), 64);
+        assert!(other.decrypt(&sealed).is_err());
+        assert!(vault.decrypt(b"L68\n").is_err());
+
+        assert!(Vault::from_hex("0011").is_err());
+        assert!(Vault::from_hex("zz").is_err());
+        let missing = Vault::load(Path::new("/nonexistent/.vault-key"))
+            .err()
+            .unwrap();
+        assert!(missing.contains("ask a teammate"));
+    }
+
+    #[test]
+    fn key_file() {
+        let path = std::env::temp_dir().join(format!("rustaoc-key-{}", std::process::id()));
+        write_key(&path, KEY).unwrap();
+        assert!(write_key(&path, KEY).is_err());
+        assert!(Vault::load(&path).is_ok());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn round_trip() {
+        let data = std::env::temp_dir().join(format!("rustaoc-vault-{}", std::process::id()));
+        let directory = data.join("2025").join("default");
+        std::fs::create_dir_all(&directory).unwrap();
+        let input = directory.join("day01.txt");
+        let answers = directory.join("answers.txt");
+        std::fs::write(&input, "L68\nL30\n").unwrap();
+        std::fs::write(&answers, "1 1 3\n").unwrap();
+        let vault = Vault::from_hex(KEY).unwrap();
+
+        assert_eq!(encrypt_all(&vault, &data, false).unwrap(), [sealed(&input)]);
+        assert!(!input.exists() && answers.exists());
+
+        assert_eq!(
+            decrypt_all(&vault, &data).unwrap(),
+            std::slice::from_ref(&input)
+        );
+        assert_eq!(std::fs::read_to_string(&input).unwrap(), "L68\nL30\n");
+
+        // The encrypted file only changes with the input.
+        let before = std::fs::read(sealed(&input)).unwrap();
+        assert!(encrypt_all(&vault, &data, true).unwrap().is_empty());
+        assert_eq!(std::fs::read(sealed(&input)).unwrap(), before);
+        std::fs::write(&input, "R48\n").unwrap();
+        assert_eq!(encrypt_all(&vault, &data, true).unwrap(), [sealed(&input)]);
+
+        std::fs::remove_dir_all(&data).unwrap();
+    }
+}
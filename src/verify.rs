@@ -0,0 +1,154 @@
+use crate::answer::Answer;
+use crate::layout::ProfileArgs;
+use crate::manifest::Manifest;
+use crate::runner::{answer_prefix, check_input, format_answer, parse, read_input};
+use crate::solution::registry;
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Args, Debug)]
+pub(crate) struct VerifyArgs {
+    /// Year of the puzzles
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Only verify this day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Answers manifest, defaults to data/<year>/<profile>/answers.txt
+    #[arg(short, long)]
+    manifest: Option<PathBuf>,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// Outcome of checking an answer against the manifest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Status {
+    /// The answer is the expected one, or neither exists.
+    Pass,
+    /// The answer differs from the expected one, or is missing.
+    Fail,
+    /// The manifest has no expected answer.
+    Unknown,
+}
+
+impl Status {
+    pub(crate) fn of(answer: Option<&Answer>, expected: Option<&Answer>) -> Status {
+        match (answer, expected) {
+            (None, None) => Status::Pass,
+            (Some(_), None) => Status::Unknown,
+            (answer, Some(expected)) if answer == Some(expected) => Status::Pass,
+            (_, Some(_)) => Status::Fail,
+        }
+    }
+}
+
+/// Runs every registered day of a year against its input and checks the
+/// answers against the manifest.
+pub(crate) fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let layout = args.profile.layout();
+    let path = match &args.manifest {
+        Some(path) => path.clone(),
+        None => layout.answers(args.year),
+    };
+    let manifest = Manifest::load(&path)?;
+
+    let mut failures = 0;
+    for ((year, day), puzzle) in registry() {
+        if year != args.year || args.day.is_some_and(|d| d != day) {
+            continue;
+        }
+
+        let path = layout.input(year, day);
+        let input = check_input(&puzzle, &read_input(&path)?, &path);
+        let solution = parse(puzzle.parse, &input, &path)?;
+        for part in [1, 2] {
+            let answer = solution.solve(part);
+            let expected = manifest.get(day, part);
+            let status = match Status::of(answer.as_ref(), expected) {
+                Status::Pass => String::from("ok"),
+                Status::Fail => {
+                    failures += 1;
+                    format!("FAIL, expected {}", format_answer(expected))
+                }
+                Status::Unknown => String::from("unknown, no expected answer"),
+            };
+            println!(
+                "{}{} ({})",
+                answer_prefix(year, day, part),
+                format_answer(answer.as_ref()),
+                status
+            );
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        _ => Err(format!(
+            "{} answer(s) differ from {}",
+            failures,
+            path.display()
+        )),
+    }
+}
+
+/// Checks the answer of a part on the default input against the default
+/// manifest, skipped when the input is encrypted and there is no vault key.
+#[cfg(test)]
+pub(crate) fn assert_answer(year: u16, day: u8, part: u8) {
+    let layout = crate::layout::Layout::default();
+    let manifest = Manifest::load(&layout.answers(year)).unwrap();
+    let puzzle = registry()[&(year, day)];
+
+    let path = layout.input(year, day);
+    let key = crate::vault::key_path(None);
+    if crate::vault::is_sealed(&path) && !key.exists() {
+        eprintln!(
+            "skipped {} day {:02} part {}: {} is encrypted and there is no vault key {}",
+            year,
+            day,
+            part,
+            path.display(),
+            key.display()
+        );
+        return;
+    }
+    let input = check_input(&puzzle, &read_input(&path).unwrap(), &path);
+    let solution = parse(puzzle.parse, &input, &path).unwrap_or_else(|e| panic!("{}", e));
+    let answer = solution.solve(part);
+
+    assert_eq!(
+        answer.as_ref(),
+        manifest.get(day, part),
+        "{} day {:02} part {}",
+        year,
+        day,
+        part
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::verify::Status;
+
+    #[test]
+    fn status() {
+        let answer = Answer::from(1031);
+
+        assert_eq!(
+            Status::of(Some(&answer), Some(&Answer::from("1031"))),
+            Status::Pass
+        );
+        assert_eq!(
+            Status::of(Some(&answer), Some(&Answer::from(5831))),
+            Status::Fail
+        );
+        assert_eq!(Status::of(None, Some(&answer)), Status::Fail);
+        assert_eq!(Status::of(Some(&answer), None), Status::Unknown);
+        assert_eq!(Status::of(None, None), Status::Pass);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::answer::Answer;
+use crate::executor::parse_answer;
+use crate::layout::ProfileArgs;
+use crate::runner::format_answer;
+use crate::vault;
+use clap::Args;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+#[derive(Args, Debug)]
+pub(crate) struct WatchArgs {
+    /// Year of the puzzle
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Day of the puzzle
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Command running the solutions, followed by the `run` arguments of the day
+    #[arg(short, long, default_value = "cargo run --release --quiet --")]
+    command: String,
+
+    /// Other files to watch, besides the input and the source of the day
+    #[arg(short, long)]
+    watch: Vec<PathBuf>,
+
+    /// Milliseconds between two checks of the watched files
+    #[arg(short, long, default_value_t = 500)]
+    interval: u64,
+
+    #[command(flatten)]
+    profile: ProfileArgs,
+}
+
+/// What is known of a watched file: when it was last modified and its size,
+/// or `None` while it does not exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// The watched files whose stamp differs from the previous one.
+fn changed<'a>(paths: &'a [PathBuf], previous: &[Stamp], current: &[Stamp]) -> Vec<&'a Path> {
+    paths
+        .iter()
+        .zip(previous.iter().zip(current))
+        .filter(|(_, (previous, current))| previous != current)
+        .map(|(path, _)| path.as_path())
+        .collect()
+}
+
+/// Describes the answer of a part against the one of the previous run.
+fn describe(part: u8, previous: Option<&Option<Answer>>, current: &Option<Answer>) -> String {
+    let answer = format_answer(current.as_ref());
+    match previous {
+        None => format!("part {}: {}", part, answer),
+        Some(previous) if previous == current => format!("part {}: {} (unchanged)", part, answer),
+        Some(previous) => format!(
+            "part {}: {} (was {})",
+            part,
+            answer,
+            format_answer(previous.as_ref())
+        ),
+    }
+}
+
+/// Runs the day through the command, returning the answer of each part, or
+/// `None` for a part without an answer line.
+fn run_day(args: &WatchArgs, command: &[&str]) -> Result<[Option<Option<Answer>>; 2], String> {
+    let (program, arguments) = command
+        .split_first()
+        .ok_or_else(|| String::from("the command is empty"))?;
+    let output = Command::new(program)
+        .args(arguments)
+        .args(["run", "--year", &args.year.to_string()])
+        .args(["--day", &args.day.to_string()])
+        .args(["--profile", &args.profile.profile])
+        .output()
+        .map_err(|e| format!("cannot run {}: {}", program, e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        return Err(format!(
+            "exited with {}\n{}{}",
+            output.status,
+            stdout,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok([1, 2].map(|part| parse_answer(&stdout, args.year, args.day, part)))
+}
+
+/// Re-runs a day whenever its input, plain or encrypted, its source or
+/// another watched file changes, printing how the answers changed since the
+/// previous run.
+pub(crate) fn watch(args: &WatchArgs) -> Result<(), String> {
+    let command: Vec<&str> = args.command.split_whitespace().collect();
+    let layout = args.profile.layout();
+    let input = layout.input(args.year, args.day);
+    // The input may be plain, encrypted in the vault, or both.
+    let mut paths = vec![
+        vault::sealed(&input),
+        input,
+        PathBuf::from(format!("src/year{}/day{:02}.rs", args.year, args.day)),
+    ];
+    paths.extend(args.watch.iter().cloned());
+
+    let mut stamps: Vec<Stamp> = paths.iter().map(|path| stamp(path)).collect();
+    let mut answers: [Option<Option<Answer>>; 2] = [None, None];
+    let mut reason = String::from("first run");
+    loop {
+        println!("--- {} day {:02}: {} ---", args.year, args.day, reason);
+        match run_day(args, &command) {
+            Ok(current) => {
+                for (i, answer) in current.iter().enumerate() {
+                    match answer {
+                        Some(answer) => {
+                            println!("{}", describe(i as u8 + 1, answers[i].as_ref(), answer))
+                        }
+                        None => println!("part {}: no answer printed", i + 1),
+                    }
+                }
+                answers = current;
+            }
+            Err(error) => println!("{}", error),
+        }
+
+        let changes = loop {
+            std::thread::sleep(Duration::from_millis(args.interval));
+            let current: Vec<Stamp> = paths.iter().map(|path| stamp(path)).collect();
+            let changes: Vec<String> = changed(&paths, &stamps, &current)
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            stamps = current;
+            if !changes.is_empty() {
+                break changes;
+            }
+        };
+        reason = format!("{} changed", changes.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::watch::{changed, describe, stamp};
+    use std::path::PathBuf;
+
+    #[test]
+    fn changes() {
+        let dir = std::env::temp_dir().join(format!("rustaoc-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let paths = vec![dir.join("day01.txt"), dir.join("day01.rs")];
+        std::fs::write(&paths[0], "L68\n").unwrap();
+        let before: Vec<_> = paths.iter().map(|path| stamp(path)).collect();
+        assert!(changed(&paths, &before, &before).is_empty());
+
+        std::fs::write(&paths[0], "L68\nR48\n").unwrap();
+        std::fs::write(&paths[1], "").unwrap();
+        let after: Vec<_> = paths.iter().map(|path| stamp(path)).collect();
+        assert_eq!(
+            changed(&paths, &before, &after),
+            paths.iter().map(PathBuf::as_path).collect::<Vec<_>>()
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn answer_diffs() {
+        let answer = Some(Answer::from(3));
+        assert_eq!(describe(1, None, &answer), "part 1: 3");
+        assert_eq!(
+            describe(1, Some(&Some(Answer::from(3))), &answer),
+            "part 1: 3 (unchanged)"
+        );
+        assert_eq!(describe(2, Some(&None), &answer), "part 2: 3 (was -)");
+        assert_eq!(describe(2, Some(&answer), &None), "part 2: - (was 3)");
+    }
+}
@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{Line, ParseError, lines};
+use crate::utils::random::Random;
+use crate::utils::trace::trace;
 use regex::Regex;
 
 /**
@@ -59,16 +64,16 @@ left pointing at 0 after any rotation in the sequence.
 For example, suppose the attached document contained the following
 rotations:
 
-L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
+    L68
+    L30
+    R48
+    L5
+    R60
+    L55
+    L1
+    L99
+    R14
+    L82
 
 Following these rotations would cause the dial to move as follows:
 
@@ -130,143 +135,85 @@ would cause the dial to point at 0 ten times before returning back to 50!
 
 Using password method 0x434C49434B, what is the password to open the door?
 */
-pub(crate) fn read_password<T>(lines: T) -> Option<i32>
+pub struct Day01 {
+    rotations: Vec<i32>,
+}
+
+impl Solution for Day01 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let rotations = read_rotations(lines(input))?;
+        Ok(Day01 { rotations })
+    }
+
+    fn part1(&self) -> Option<Answer> {
+        Some(read_password(&self.rotations).into())
+    }
+
+    fn part2(&self) -> Option<Answer> {
+        Some(read_password_0x434c49434b(&self.rotations).into())
+    }
+}
+
+/// Reads the rotations as signed click counts: right is positive, left is negative.
+pub(crate) fn read_rotations<'a, T>(lines: T) -> Result<Vec<i32>, ParseError>
 where
-    T: Iterator<Item = String>,
+    T: Iterator<Item = Line<'a>>,
 {
-    let regex = Regex::new(r"([RL])(\d+)").unwrap();
+    let regex = Regex::new(r"^([RL])(\d+)$").unwrap();
+
+    let mut rotations = Vec::new();
+    for line in lines {
+        let caps = line.captures(&regex, "a rotation such as `L68`")?;
+        let count = line.parse::<i32>(caps.get(2).unwrap().as_str())?;
+        match &caps[1] {
+            "R" => rotations.push(count),
+            _ => rotations.push(-count),
+        }
+    }
+    Ok(rotations)
+}
 
+pub(crate) fn read_password(rotations: &[i32]) -> i32 {
     let mut safe = 50;
     let mut password = 0;
 
-    for line in lines {
-        //println!("line: {}", line);
-        let Some(caps) = regex.captures(line.as_str()) else {
-            println!("no match! for line {}", line);
-            return None;
-        };
-        let count = caps.get(2).unwrap().as_str().parse::<i32>().unwrap();
-        match caps.get(1).unwrap().as_str() {
-            "R" => safe += count,
-            "L" => safe -= count,
-            _ => println!("invalid line: {}", line),
-        }
+    for rotation in rotations {
+        safe += rotation;
 
-        //println!("safe: {}", safe);
+        trace!("safe: {}", safe);
         if safe % 100 == 0 {
             password += 1;
         }
     }
-    Some(password)
+    password
 }
 
-pub(crate) fn read_password_0x434c49434b<T>(lines: T) -> Option<i32>
-where
-    T: Iterator<Item = String>,
-{
-    let regex = Regex::new(r"([RL])(\d+)").unwrap();
-
+pub(crate) fn read_password_0x434c49434b(rotations: &[i32]) -> i32 {
     let mut safe = 50;
     let mut password = 0;
 
-    for line in lines {
-        let Some(caps) = regex.captures(line.as_str()) else {
-            println!("no match! for line {}", line);
-            return None;
-        };
-
-        let mut count = caps.get(2).unwrap().as_str().parse::<i32>().unwrap();
-        match caps.get(1).unwrap().as_str() {
-            "R" => {
-                while count > 0 {
-                    safe += 1;
-                    if safe % 100 == 0 {
-                        password += 1;
-                    }
-                    count -= 1;
-                }
+    for &rotation in rotations {
+        let step = rotation.signum();
+        let mut count = rotation.abs();
+        while count > 0 {
+            safe += step;
+            if safe % 100 == 0 {
+                password += 1;
             }
-            "L" => {
-                while count > 0 {
-                    safe -= 1;
-                    if safe % 100 == 0 {
-                        password += 1;
-                    }
-                    count -= 1;
-                }
-            }
-            _ => println!("invalid line: {}", line),
+            count -= 1;
         }
 
-        println!("line -> safe: {} {}", line, safe);
-        println!("password: {}", password);
+        trace!(
+            "rotation {} -> safe: {}, password: {}",
+            rotation, safe, password
+        );
     }
-    Some(password)
+    password
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use crate::year2025::day01::{read_password, read_password_0x434c49434b};
-    use std::path::Path;
-
-    #[test]
-    fn input_example() {
-        let input = r#"L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82"#;
-
-        let lines = input.split("\n");
-
-        let password = match read_password(lines.map(String::from)) {
-            Some(value) => value,
-            None => return,
-        };
-
-        assert_eq!(password, 3);
-
-        let lines = input.split("\n");
-
-        let password = match read_password_0x434c49434b(lines.map(String::from)) {
-            Some(value) => value,
-            None => return,
-        };
-
-        assert_eq!(password, 6);
-    }
-
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day01.txt");
-
-        let lines = read_lines(path);
-
-        let password = match read_password(lines.into_iter()) {
-            Some(value) => value,
-            None => return,
-        };
-
-        assert_eq!(password, 1031);
-    }
-
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day01.txt");
-
-        let lines = read_lines(path);
-
-        let password = match read_password_0x434c49434b(lines.into_iter()) {
-            Some(value) => value,
-            None => return,
-        };
-
-        assert_eq!(password, 5831);
-    }
+/// `size` rotations of up to 999 clicks.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}{}\n", random.pick(&['L', 'R']), random.range(1..=999)))
+        .collect()
 }
@@ -1,3 +1,10 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{ParseError, lines};
+use crate::utils::random::Random;
+use crate::utils::trace::{debug, trace};
+use std::collections::HashSet;
+
 /**
 --- Day 2: Gift Shop ---
 
@@ -19,9 +26,9 @@ They've even checked most of the product ID ranges already; they only have
 a few product ID ranges (your puzzle input) that you'll need to check. For
 example:
 
-11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
-1698522-1698528,446443-446449,38593856-38593862,565653-565659,
-824824821-824824827,2121212118-2121212124
+    11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+    1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+    824824821-824824827,2121212118-2121212124
 
 (The ID ranges are wrapped here for legibility; in your input, they appear
 on a single long line.)
@@ -82,84 +89,78 @@ Adding up all the invalid IDs in this example produces 4174379265.
 
 What do you get if you add up all of the invalid IDs using these new rules?
  */
-#[cfg(test)]
-mod tests {
-    use regex::Regex;
-    use std::collections::HashSet;
-
-    fn check_gift_shop(input: &str, mut func: impl FnMut(i64) -> bool) -> i64 {
-        let regex = Regex::new(r"(\d+)-(\d+)").unwrap();
-        let ids = input
-            .split(",")
-            .map(|s| {
-                let result = regex.captures(s).unwrap();
-                let left = result[1].parse::<i64>().unwrap();
-                let right = result[2].parse::<i64>().unwrap();
-                (left, right)
-            })
-            .collect::<Vec<_>>();
-
-        let mut invalid_ids = HashSet::new();
-        for id in ids {
-            //println!("x = ({}, {})", id.0, id.1);
-            for i in id.0..=id.1 {
-                if func(i) {
-                    invalid_ids.insert(i);
-                    //println!("invalid = {}", i);
-                }
-            }
-        }
-        invalid_ids.iter().sum()
-    }
-
-    use std::path::Path;
+pub struct Day02 {
+    ranges: Vec<(i64, i64)>,
+}
 
-    fn invalid_part2(id: i64) -> bool {
-        let s: String = id.to_string();
-        let new_string = s.clone() + &s;
-        new_string[1..(2 * s.len() - 1)].contains(&s)
+impl Solution for Day02 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day02 {
+            ranges: read_ranges(input)?,
+        })
     }
 
-    fn invalid_part1(id: i64) -> bool {
-        let s: String = id.to_string();
-        let length = s.len();
-        length % 2 == 0 && s[..length / 2] == s[length / 2..]
+    fn part1(&self) -> Option<Answer> {
+        Some(check_gift_shop(&self.ranges, invalid_part1).into())
     }
 
-    #[test]
-    fn input_example() {
-        let input = r#"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
-1698522-1698528,446443-446449,38593856-38593862,565653-565659,
-824824821-824824827,2121212118-2121212124"#;
-
-        let invalid_ids = check_gift_shop(input, |s| invalid_part1(s));
-
-        assert_eq!(invalid_ids, 1227775554);
-
-        let invalid_ids = check_gift_shop(input, |s| invalid_part2(s));
-
-        assert_eq!(invalid_ids, 4174379265);
+    fn part2(&self) -> Option<Answer> {
+        Some(check_gift_shop(&self.ranges, invalid_part2).into())
     }
+}
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day02.txt");
-
-        let input = std::fs::read_to_string(path).unwrap();
-
-        let invalid_ids = check_gift_shop(input.as_str(), |s| invalid_part1(s));
-
-        assert_eq!(invalid_ids, 38437576669);
+/// Reads the comma-separated ranges, which may be wrapped over several lines.
+fn read_ranges(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let mut ranges = Vec::new();
+    for line in lines(input) {
+        for range in line
+            .text
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            let (left, right) = line.split_once(range, "-")?;
+            ranges.push((line.parse::<i64>(left)?, line.parse::<i64>(right)?));
+        }
     }
+    Ok(ranges)
+}
 
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day02.txt");
+fn check_gift_shop(ranges: &[(i64, i64)], mut func: impl FnMut(i64) -> bool) -> i64 {
+    let mut invalid_ids = HashSet::new();
+    for id in ranges {
+        debug!("x = ({}, {})", id.0, id.1);
+        for i in id.0..=id.1 {
+            if func(i) {
+                invalid_ids.insert(i);
+                trace!("invalid = {}", i);
+            }
+        }
+    }
+    invalid_ids.iter().sum()
+}
 
-        let input = std::fs::read_to_string(path).unwrap();
+fn invalid_part2(id: i64) -> bool {
+    let s: String = id.to_string();
+    let new_string = s.clone() + &s;
+    new_string[1..(2 * s.len() - 1)].contains(&s)
+}
 
-        let invalid_ids = check_gift_shop(input.as_str(), |s| invalid_part2(s));
+fn invalid_part1(id: i64) -> bool {
+    let s: String = id.to_string();
+    let length = s.len();
+    length.is_multiple_of(2) && s[..length / 2] == s[length / 2..]
+}
 
-        assert_eq!(invalid_ids, 49046150754);
-    }
+/// `size` ranges of IDs of up to ten digits, each of up to 100000 IDs.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = random.range(1..=10) as u32;
+            let start = random.range(1..=10i64.pow(digits) - 1);
+            let end = start + random.range(0..=100_000);
+            format!("{}-{}", start, end)
+        })
+        .collect();
+    format!("{}\n", ranges.join(","))
 }
@@ -1,3 +1,11 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{ParseError, lines};
+use crate::utils::random::Random;
+use crate::utils::trace::debug;
+use itertools::Itertools;
+use std::collections::VecDeque;
+
 /**
 --- Day 3: Lobby ---
 
@@ -91,86 +99,118 @@ The total output joltage is now much larger: 987654321111 + 811111111119 +
 
 What is the new total output joltage?
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use std::collections::VecDeque;
-    use std::path::Path;
-
-    #[test]
-    fn input_example() {
-        let input = r#"987654321111111
-811111111111119
-234234234234278
-818181911112111"#;
-
-        let lines = input.split("\n");
-
-        let sum = joltage_lobby(lines.map(String::from), 2);
-        assert_eq!(sum, 357);
-
-        let lines = input.split("\n");
-        let sum = joltage_lobby(lines.map(String::from), 12);
-        assert_eq!(sum, 3121910778619);
-    }
-
-    fn joltage_lobby<T>(lines: T, n: usize) -> u64
-    where
-        T: Iterator<Item = String>,
-    {
-        let mut sum: u64 = 0;
+pub struct Day03 {
+    banks: Vec<String>,
+}
 
-        for line in lines {
-            let joltage = find_joltage(&line, n);
-            println!("{} -> joltage: {}", line, joltage);
-            sum += joltage;
+impl Solution for Day03 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut banks = Vec::new();
+        for line in lines(input) {
+            line.expect_chars("0123456789")?;
+            if line.text.len() < BATTERIES {
+                let message = format!("expected at least {} batteries", BATTERIES);
+                return Err(line.error(line.text, message));
+            }
+            banks.push(line.text.to_string());
         }
-        sum
+        Ok(Day03 { banks })
     }
 
-    fn find_joltage(line: &String, n: usize) -> u64 {
-        let mut removals_left = line.len() - n;
-        let mut stack: VecDeque<u8> = VecDeque::new();
-        for x in line.as_bytes() {
-            let d = x - '0' as u8;
-            while !stack.is_empty() && removals_left > 0 && stack.back().unwrap() < &d {
-                stack.pop_back();
-                removals_left -= 1;
-            }
-            stack.push_back(d);
-        }
+    fn part1(&self) -> Option<Answer> {
+        Some(joltage_lobby(self.banks.iter().cloned(), 2).into())
+    }
 
-        while stack.len() > n {
-            stack.pop_back();
-        }
+    fn part2(&self) -> Option<Answer> {
+        Some(joltage_lobby(self.banks.iter().cloned(), BATTERIES).into())
+    }
+}
 
-        let mut joltage: u64 = 0;
-        for d in stack {
-            joltage *= 10;
-            joltage += d as u64;
-        }
-        joltage
+/// Tries every choice of batteries in each bank, to check `find_joltage`.
+pub(crate) struct BruteForce {
+    banks: Vec<String>,
+}
+
+impl Solution for BruteForce {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(BruteForce {
+            banks: Day03::parse(input)?.banks,
+        })
     }
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day03.txt");
+    fn part1(&self) -> Option<Answer> {
+        Some(self.total(2).into())
+    }
 
-        let lines = read_lines(path);
+    fn part2(&self) -> Option<Answer> {
+        Some(self.total(BATTERIES).into())
+    }
+}
 
-        let sum = joltage_lobby(lines.into_iter(), 2);
+impl BruteForce {
+    fn total(&self, n: usize) -> u64 {
+        self.banks
+            .iter()
+            .map(|bank| {
+                bank.bytes()
+                    .combinations(n)
+                    .map(|digits| digits.iter().fold(0, |j, d| j * 10 + (d - b'0') as u64))
+                    .max()
+                    .unwrap()
+            })
+            .sum()
+    }
+}
 
-        assert_eq!(sum, 17316);
+/// Up to `size` banks of at least `BATTERIES` batteries, `size` more at most.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..1 + random.below(size) {
+        for _ in 0..BATTERIES + random.below(size + 1) {
+            input.push((b'1' + random.below(9) as u8) as char);
+        }
+        input.push('\n');
     }
+    input
+}
 
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day03.txt");
+/// Number of batteries turned on in each bank by the second part.
+const BATTERIES: usize = 12;
 
-        let lines = read_lines(path);
+fn joltage_lobby<T>(lines: T, n: usize) -> u64
+where
+    T: Iterator<Item = String>,
+{
+    let mut sum: u64 = 0;
 
-        let sum = joltage_lobby(lines.into_iter(), 12);
+    for line in lines {
+        let joltage = find_joltage(&line, n);
+        debug!("{} -> joltage: {}", line, joltage);
+        sum += joltage;
+    }
+    sum
+}
+
+fn find_joltage(line: &str, n: usize) -> u64 {
+    let mut removals_left = line.len() - n;
+    let mut stack: VecDeque<u8> = VecDeque::new();
+    for x in line.as_bytes() {
+        let d = x - b'0';
+        while !stack.is_empty() && removals_left > 0 && stack.back().unwrap() < &d {
+            stack.pop_back();
+            removals_left -= 1;
+        }
+        stack.push_back(d);
+    }
+
+    while stack.len() > n {
+        stack.pop_back();
+    }
 
-        assert_eq!(sum, 171741365473332);
+    let mut joltage: u64 = 0;
+    for d in stack {
+        joltage *= 10;
+        joltage += d as u64;
     }
+    joltage
 }
@@ -1,3 +1,12 @@
+use crate::answer::Answer;
+use crate::simulation::Simulation;
+use crate::solution::Solution;
+use crate::utils::parse::{ParseError, lines};
+use crate::utils::point::{Map, Point2D};
+use crate::utils::random::Random;
+use itertools::iproduct;
+use std::collections::HashSet;
+
 /**
 --- Day 4: Printing Department ---
 
@@ -198,112 +207,141 @@ Start with your original diagram. How many rolls of paper in total can be
 removed by the Elves and their forklifts?
 
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use crate::utils::point::{Map, Point2D};
-    use itertools::iproduct;
-    use std::collections::HashSet;
-    use std::path::Path;
-
-    #[test]
-    fn input_example() {
-        let input = r#"..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@."#;
-
-        let lines = input.split("\n");
-        let forklift = part_one(lines.into_iter().map(str::to_string));
-
-        assert_eq!(forklift, 13);
-
-        let lines = input.split("\n");
-        let forklift = part_two(lines.into_iter().map(str::to_string));
-
-        assert_eq!(forklift, 43);
+pub struct Day04 {
+    map: Map,
+}
+
+impl Solution for Day04 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day04 {
+            map: read_map(input)?,
+        })
     }
 
-    fn part_one<T>(lines: T) -> usize
-    where
-        T: Iterator<Item = String>,
-    {
-        let map_vector: Vec<Vec<char>> = lines.map(|l| l.chars().collect()).collect();
-        let map = Map::new(map_vector);
+    fn part1(&self) -> Option<Answer> {
+        Some(part_one(&self.map).into())
+    }
 
-        forklift(&map).len()
+    fn part2(&self) -> Option<Answer> {
+        Some(part_two(self.map.clone()).into())
     }
+}
 
-    fn part_two<T>(lines: T) -> usize
-    where
-        T: Iterator<Item = String>,
-    {
-        let map_vector: Vec<Vec<char>> = lines.map(|l| l.chars().collect()).collect();
-        let mut map = Map::new(map_vector);
-
-        let mut rolls = 0;
-        loop {
-            let to_remove = forklift(&map);
-            if to_remove.is_empty() {
-                break;
-            }
+fn read_map(input: &str) -> Result<Map, ParseError> {
+    let mut map_vector: Vec<Vec<char>> = Vec::new();
+    for line in lines(input) {
+        line.expect_chars(".@")?;
+        let row: Vec<char> = line.text.chars().collect();
+        if let Some(first) = map_vector.first()
+            && first.len() != row.len()
+        {
+            let message = format!("expected {} cells, found {}", first.len(), row.len());
+            return Err(line.error(line.text, message));
+        }
+        map_vector.push(row);
+    }
+    if map_vector.is_empty() {
+        return Err(ParseError::missing(input, "expected the grid of rolls"));
+    }
+    Ok(Map::new(map_vector))
+}
 
-            rolls += to_remove.len();
+/// Builds the removal waves of the input, for the step debugger.
+pub(crate) fn simulation(input: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(Box::new(Removal::new(read_map(input)?)))
+}
 
-            for x in to_remove {
-                map.set(&x, ',');
-            }
-        }
+/// The rolls removed by waves: each wave removes every roll a forklift can
+/// access, which frees the rolls around them for the next one.
+struct Removal {
+    map: Map,
+    /// The rolls the next wave removes.
+    accessible: HashSet<Point2D>,
+    waves: usize,
+    removed: usize,
+}
 
-        rolls
+impl Removal {
+    fn new(map: Map) -> Self {
+        Removal {
+            accessible: forklift(&map),
+            map,
+            waves: 0,
+            removed: 0,
+        }
     }
+}
 
-    fn forklift(map: &Map) -> HashSet<Point2D> {
-        let mut forklift = HashSet::new();
-        for (x, y) in iproduct!(0..map.x_max(), 0..map.y_max()) {
-            let p = Point2D::new(x, y);
-            if map.get(&p).unwrap() == '@' {
-                let count = p
-                    .neighbors()
-                    .iter()
-                    .filter(|p| p.valid(map.x_max(), map.x_max()))
-                    .map(|p| map.get(p))
-                    .filter(|c| c.unwrap() == '@')
-                    .count();
-
-                if count < 4 {
-                    forklift.insert(p);
-                }
-            }
+impl Simulation for Removal {
+    fn step(&mut self) -> bool {
+        if self.accessible.is_empty() {
+            return false;
         }
-        forklift
-    }
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day04.txt");
+        self.waves += 1;
+        self.removed += self.accessible.len();
+        for x in &self.accessible {
+            self.map.set(x, ',');
+        }
+        self.accessible = forklift(&self.map);
+        true
+    }
 
-        let lines = read_lines(path);
+    fn map(&self) -> &Map {
+        &self.map
+    }
 
-        let forklift = part_one(lines.into_iter());
+    fn frontier(&self) -> Vec<Point2D> {
+        self.accessible.iter().copied().collect()
+    }
 
-        assert_eq!(forklift, 1419);
+    fn status(&self) -> String {
+        format!("wave {}, {} rolls removed", self.waves, self.removed)
     }
+}
 
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day04.txt");
+fn part_one(map: &Map) -> usize {
+    forklift(map).len()
+}
 
-        let lines = read_lines(path);
+fn part_two(map: Map) -> usize {
+    let mut removal = Removal::new(map);
+    while removal.step() {}
+    removal.removed
+}
 
-        let forklift = part_two(lines.into_iter());
+fn forklift(map: &Map) -> HashSet<Point2D> {
+    let mut forklift = HashSet::new();
+    for (x, y) in iproduct!(0..map.x_max(), 0..map.y_max()) {
+        let p = Point2D::new(x, y);
+        if map.get(&p).unwrap() == '@' {
+            let count = p
+                .neighbors()
+                .iter()
+                .filter(|p| p.valid(map.x_max(), map.y_max()))
+                .map(|p| map.get(p))
+                .filter(|c| c.unwrap() == '@')
+                .count();
+
+            if count < 4 {
+                forklift.insert(p);
+            }
+        }
+    }
+    forklift
+}
 
-        assert_eq!(forklift, 8739);
+/// A grid of 1 to `size` rows of 1 to `size` cells each, two thirds of them
+/// rolls.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let width = 1 + random.below(size);
+    let height = 1 + random.below(size);
+    let mut input = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            input.push(if random.chance(2, 3) { '@' } else { '.' });
+        }
+        input.push('\n');
     }
+    input
 }
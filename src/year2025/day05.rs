@@ -1,3 +1,10 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{ParseError, lines};
+use crate::utils::random::Random;
+use crate::utils::trace::{debug, trace};
+use std::collections::VecDeque;
+
 #[derive(Debug, Clone)]
 struct Range {
     lower: u64,
@@ -111,164 +118,127 @@ ingredient ID ranges consider a total of 14 ingredient IDs to be fresh.
 Process the database file again. How many ingredient IDs are considered to
 be fresh according to the fresh ingredient ID ranges?
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use crate::year2025::day05::Range;
-    use regex::Regex;
-    use std::collections::VecDeque;
-    use std::path::Path;
-
-    #[test]
-    fn input_example() {
-        let input = r#"3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32"#;
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-
-        let fresh = cafeteria_part1(lines);
-
-        assert_eq!(fresh, 3);
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
+pub struct Day05 {
+    ranges: Vec<Range>,
+    ids: Vec<u64>,
+}
 
-        let fresh = cafeteria_part2(lines);
+impl Solution for Day05 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (ranges, ids) = read_input(input)?;
+        Ok(Day05 { ranges, ids })
+    }
 
-        assert_eq!(fresh, 14);
+    fn part1(&self) -> Option<Answer> {
+        Some(cafeteria_part1(&self.ranges, &self.ids).into())
     }
 
-    fn cafeteria_part1<T>(lines: T) -> i32
-    where
-        T: Iterator<Item = String>,
-    {
-        let regex = Regex::new(r"(\d+)-(\d+)").unwrap();
-
-        let mut ranges: Vec<Range> = Vec::new();
-        let mut ids: Vec<u64> = Vec::new();
-
-        for line in lines {
-            let capture = regex.captures(&line);
-            if capture.is_some() {
-                let unwrap = capture.unwrap();
-                ranges.push(Range::new(
-                    unwrap[1].parse().unwrap(),
-                    unwrap[2].parse().unwrap(),
-                ));
-            } else if !line.is_empty() {
-                ids.push(line.parse().unwrap());
-            }
-        }
+    fn part2(&self) -> Option<Answer> {
+        Some(cafeteria_part2(&self.ranges).into())
+    }
+}
 
-        println!("Ranges: {:?}", ranges);
-        println!("Ids: {:?}", ids);
+/// Reads the fresh ranges, then the available ids after a blank line.
+fn read_input(input: &str) -> Result<(Vec<Range>, Vec<u64>), ParseError> {
+    let mut ranges: Vec<Range> = Vec::new();
+    let mut ids: Vec<u64> = Vec::new();
 
-        let mut fresh = 0;
-        for id in ids {
-            if ranges.iter().any(|range| range.contains(id)) {
-                println!("Fresh id: {:?}", id);
-                fresh += 1;
-            }
+    let mut lines = lines(input);
+    for line in lines.by_ref() {
+        if line.text.is_empty() {
+            break;
         }
-        fresh
+        let (lower, upper) = line.split_once(line.text, "-")?;
+        ranges.push(Range::new(line.parse(lower)?, line.parse(upper)?));
+    }
+    for line in lines {
+        ids.push(line.parse(line.text)?);
     }
 
-    fn cafeteria_part2<T>(lines: T) -> u64
-    where
-        T: Iterator<Item = String>,
-    {
-        let regex = Regex::new(r"(\d+)-(\d+)").unwrap();
-
-        let mut ranges: Vec<Range> = Vec::new();
-
-        for line in lines {
-            let capture = regex.captures(&line);
-            if capture.is_some() {
-                let unwrap = capture.unwrap();
-                ranges.push(Range::new(
-                    unwrap[1].parse().unwrap(),
-                    unwrap[2].parse().unwrap(),
-                ));
-            } else {
-                break;
-            }
-        }
-
-        println!("Ranges: {:?}", ranges);
+    debug!("Ranges: {:?}", ranges);
+    debug!("Ids: {:?}", ids);
+    Ok((ranges, ids))
+}
 
-        let mut deque: VecDeque<Option<Range>> = VecDeque::new();
-        for r in ranges {
-            deque.push_back(Some(r));
+fn cafeteria_part1(ranges: &[Range], ids: &[u64]) -> i32 {
+    let mut fresh = 0;
+    for &id in ids {
+        if ranges.iter().any(|range| range.contains(id)) {
+            trace!("Fresh id: {:?}", id);
+            fresh += 1;
         }
-        loop {
-            let size = deque.len();
-            let mut found = false;
-            for i in 0..size {
-                match &deque[i] {
-                    None => {}
-                    Some(range1) => {
-                        let mut new_range = range1.clone();
-                        for j in i + 1..size {
-                            match &deque[j] {
-                                None => {}
-                                Some(range2) => {
-                                    if new_range.intersect(&range2) {
-                                        new_range = new_range.union(&range2);
-                                        deque[j] = None;
-                                        found = true;
-                                    }
-                                }
+    }
+    fresh
+}
+
+fn cafeteria_part2(ranges: &[Range]) -> u64 {
+    let mut deque: VecDeque<Option<Range>> = VecDeque::new();
+    for r in ranges {
+        deque.push_back(Some(r.clone()));
+    }
+    loop {
+        let size = deque.len();
+        let mut found = false;
+        for i in 0..size {
+            match &deque[i] {
+                None => {}
+                Some(range1) => {
+                    let mut new_range = range1.clone();
+                    for j in i + 1..size {
+                        match &deque[j] {
+                            Some(range2) if new_range.intersect(range2) => {
+                                new_range = new_range.union(range2);
+                                deque[j] = None;
+                                found = true;
                             }
-                            deque[i] = Some(new_range.clone());
+                            _ => {}
                         }
+                        deque[i] = Some(new_range.clone());
                     }
                 }
             }
-
-            if found {
-                deque.retain(|s| s.is_some());
-            } else {
-                break;
-            }
-
-            println!("Ranges: {:?}", deque);
         }
 
-        deque
-            .iter()
-            .map(Option::as_ref)
-            .map(Option::unwrap)
-            .map(Range::len)
-            .sum()
-    }
-
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day05.txt");
-
-        let lines = read_lines(path);
-
-        let fresh = cafeteria_part1(lines.into_iter());
+        if found {
+            deque.retain(|s| s.is_some());
+        } else {
+            break;
+        }
 
-        assert_eq!(fresh, 712);
+        trace!("Ranges: {:?}", deque);
     }
 
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day05.txt");
-
-        let lines = read_lines(path);
-
-        let fresh = cafeteria_part2(lines.into_iter());
+    deque
+        .iter()
+        .map(Option::as_ref)
+        .map(Option::unwrap)
+        .map(Range::len)
+        .sum()
+}
 
-        assert_eq!(fresh, 332998283036769);
+/// `size` ranges of fresh IDs below 10^15, then five times as many IDs, half
+/// of them drawn from the ranges.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let ranges: Vec<(i64, i64)> = (0..size)
+        .map(|_| {
+            let lower = random.range(1..=1_000_000_000_000_000);
+            (lower, lower + random.range(0..=1_000_000_000_000))
+        })
+        .collect();
+    let mut input: String = ranges
+        .iter()
+        .map(|(lower, upper)| format!("{}-{}\n", lower, upper))
+        .collect();
+    input.push('\n');
+    for _ in 0..5 * size {
+        let id = match random.chance(1, 2) {
+            true => {
+                let (lower, upper) = *random.pick(&ranges);
+                random.range(lower..=upper)
+            }
+            false => random.range(1..=1_000_000_000_000_000),
+        };
+        input.push_str(&format!("{}\n", id));
     }
+    input
 }
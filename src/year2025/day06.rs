@@ -1,3 +1,11 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{Line, ParseError, lines};
+use crate::utils::random::Random;
+use crate::utils::trace::debug;
+use std::collections::VecDeque;
+use string_builder::Builder;
+
 /**
 --- Day 6: Trash Compactor ---
 
@@ -80,145 +88,173 @@ Now, the grand total is 1058 + 3253600 + 625 + 8544 = 3263827.
 Solve the problems on the math worksheet again. What is the grand total
 found by adding together all of the answers to the individual problems?
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use std::collections::VecDeque;
-    use std::path::Path;
-    use string_builder::Builder;
-    #[test]
-    fn input_example() {
-        let input = r#"123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +  "#;
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-
-        let result = trash_compactor_part_one(lines);
+pub struct Day06 {
+    numbers: Vec<Vec<u64>>,
+    operations: Vec<String>,
+    lines: Vec<String>,
+}
 
-        assert_eq!(result, 4277556);
+impl Solution for Day06 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (numbers, operations) = read_input(lines(input))?;
+        if numbers.is_empty() || operations.is_empty() {
+            let message = "expected rows of numbers, then a row of operations";
+            return Err(ParseError::missing(input, message));
+        }
+        Ok(Day06 {
+            numbers,
+            operations,
+            lines: input.lines().map(String::from).collect(),
+        })
+    }
 
-        let lines = input.split("\n").into_iter().map(str::to_string);
+    fn part1(&self) -> Option<Answer> {
+        Some(trash_compactor_part_one(&self.numbers, &self.operations).into())
+    }
 
-        let result = trash_compactor_part_two(lines);
+    fn part2(&self) -> Option<Answer> {
+        trash_compactor_part_two(self.lines.iter().cloned()).map(Answer::from)
+    }
+}
 
-        assert_eq!(result, 3263827);
+/// Reads the numbers column by column from the right, or `None` when the
+/// columns do not make up problems, such as an operation without numbers.
+fn trash_compactor_part_two<T>(lines: T) -> Option<u64>
+where
+    T: Iterator<Item = String>,
+{
+    let lines: Vec<String> = lines.collect();
+    let len = lines.iter().map(String::len).max()?;
+
+    // Pad the lines whose trailing spaces were stripped, so columns stay aligned once reversed.
+    let rev_lines: Vec<String> = lines
+        .iter()
+        .map(|s| format!("{:<len$}", s).chars().rev().collect::<String>())
+        .collect();
+    let mut builder = Builder::default();
+    for i in 0..len {
+        rev_lines
+            .iter()
+            .map(|l| l.chars().nth(i))
+            .map(Option::unwrap)
+            .for_each(|c| builder.append(c));
     }
 
-    fn trash_compactor_part_two<T>(lines: T) -> u64
-    where
-        T: Iterator<Item = String>,
+    let mut queue: VecDeque<u64> = VecDeque::new();
+    let mut result: u64 = 0;
+    for x in builder
+        .string()
+        .unwrap()
+        .split_whitespace()
+        .map(String::from)
     {
-        let rev_lines: Vec<String> = lines.map(|s| s.chars().rev().collect::<String>()).collect();
-
-        let len = rev_lines.iter().map(String::len).max().unwrap();
-        let mut builder = Builder::default();
-        for i in 0..len {
-            rev_lines
-                .iter()
-                .map(|l| l.chars().nth(i))
-                .map(Option::unwrap)
-                .for_each(|c| builder.append(c));
+        if x.ends_with('*') {
+            if let Ok(v) = x[..x.len() - 1].parse() {
+                queue.push_back(v)
+            };
+            result += queue.iter().copied().reduce(|a, b| a * b)?;
+            queue.clear();
+        } else if x.ends_with('+') {
+            if let Ok(v) = x[..x.len() - 1].parse() {
+                queue.push_back(v)
+            };
+            result += queue.iter().copied().reduce(|a, b| a + b)?;
+            queue.clear();
+        } else {
+            queue.push_back(x.parse().ok()?);
         }
+    }
+    Some(result)
+}
 
-        let mut queue: VecDeque<u64> = VecDeque::new();
-        let mut result: u64 = 0;
-        for x in builder
-            .string()
-            .unwrap()
-            .split_whitespace()
-            .map(String::from)
-        {
-            if x.ends_with('*') {
-                match x[..x.len() - 1].parse() {
-                    Ok(v) => queue.push_back(v),
-                    Err(_) => {}
-                };
-                result += queue.iter().map(|a| *a).reduce(|a, b| a * b).unwrap();
-                queue.clear();
-            } else if x.ends_with('+') {
-                match x[..x.len() - 1].parse() {
-                    Ok(v) => queue.push_back(v),
-                    Err(_) => {}
-                };
-                result += queue.iter().map(|a| *a).reduce(|a, b| a + b).unwrap();
-                queue.clear();
-            } else {
-                queue.push_back(x.parse().unwrap());
+fn trash_compactor_part_one(numbers: &[Vec<u64>], operations: &[String]) -> u64 {
+    let mut result: u64 = 0;
+    for (i, o) in operations.iter().enumerate() {
+        match o.as_str() {
+            "+" => {
+                result += numbers.iter().map(|n| n[i]).reduce(|a, b| a + b).unwrap();
+            }
+            "*" => {
+                result += numbers.iter().map(|n| n[i]).reduce(|a, b| a * b).unwrap();
             }
+            _ => unreachable!("operations are checked by read_input"),
         }
-        result
     }
+    result
+}
 
-    fn trash_compactor_part_one<T>(lines: T) -> u64
-    where
-        T: Iterator<Item = String>,
-    {
-        let (numbers, operations) = read_input(lines);
+/// Reads the rows of numbers, then the row of operations: every row has one
+/// entry per problem.
+fn read_input<'a, T>(lines: T) -> Result<(Vec<Vec<u64>>, Vec<String>), ParseError>
+where
+    T: Iterator<Item = Line<'a>>,
+{
+    let mut numbers: Vec<Vec<u64>> = Vec::new();
+    let mut operations: Vec<String> = Vec::new();
+    let mut problems = None;
+    for line in lines {
+        let row: Vec<&str> = line.text.split_whitespace().collect();
+        if *problems.get_or_insert(row.len()) != row.len() {
+            let message = format!(
+                "expected {} problems, found {}",
+                problems.unwrap(),
+                row.len()
+            );
+            return Err(line.error(line.text, message));
+        }
 
-        let mut result: u64 = 0;
-        for (i, o) in operations.iter().enumerate() {
-            match o.as_str() {
-                "+" => {
-                    result += numbers.iter().map(|n| n[i]).reduce(|a, b| a + b).unwrap();
-                }
-                "*" => {
-                    result += numbers.iter().map(|n| n[i]).reduce(|a, b| a * b).unwrap();
+        if !operations.is_empty() {
+            return Err(line.error(line.text, "expected the row of operations last"));
+        }
+        if line.text.contains("*") || line.text.contains("+") {
+            for o in row {
+                if o != "*" && o != "+" {
+                    return Err(line.error(o, format!("expected `*` or `+`, found `{}`", o)));
                 }
-                &_ => todo!(),
+                operations.push(o.to_string());
             }
+
+            debug!("Operations: {:?}", operations)
+        } else {
+            let x: Vec<u64> = row
+                .into_iter()
+                .map(|n| line.parse(n))
+                .collect::<Result<_, _>>()?;
+            debug!("Values: {:?}", x);
+            numbers.push(x);
         }
-        result
     }
+    Ok((numbers, operations))
+}
 
-    fn read_input<T>(lines: T) -> (Vec<Vec<u64>>, Vec<String>)
-    where
-        T: Iterator<Item = String>,
-    {
-        let mut numbers: Vec<Vec<u64>> = Vec::new();
-        let mut operations: Vec<String> = Vec::new();
-        for line in lines {
-            if line.contains("*") || line.contains("+") {
-                line.split_whitespace()
-                    .into_iter()
-                    .map(str::to_string)
-                    .for_each(|o| operations.push(o));
-
-                println!("Operations: {:?}", operations)
-            } else {
-                let x: Vec<u64> = line
-                    .split_whitespace()
-                    .into_iter()
-                    .map(str::parse)
-                    .map(Result::unwrap)
-                    .collect();
-                println!("Values: {:?}", x);
-                numbers.push(x);
+/// A worksheet of `size` problems of two to four numbers of up to three
+/// digits, each problem aligned left or right, with the trailing spaces kept.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let rows = random.range(2..=4) as usize;
+    let mut lines = vec![String::new(); rows + 1];
+    for problem in 0..size {
+        let numbers: Vec<String> = (0..rows)
+            .map(|_| {
+                let digits = random.range(1..=3) as u32;
+                random.range(1..=10i64.pow(digits) - 1).to_string()
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap();
+        let left = random.chance(1, 2);
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            if problem > 0 {
+                line.push(' ');
+            }
+            match left {
+                true => line.push_str(&format!("{:<width$}", number)),
+                false => line.push_str(&format!("{:>width$}", number)),
             }
         }
-        (numbers, operations)
-    }
-
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day06.txt");
-
-        let lines = read_lines(path);
-
-        let result = trash_compactor_part_one(lines.into_iter());
-
-        assert_eq!(result, 5667835681547);
-    }
-
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day06.txt");
-
-        let lines = read_lines(path);
-
-        let result = trash_compactor_part_two(lines.into_iter());
-
-        assert_eq!(result, 9434900032651);
+        let operations = &mut lines[rows];
+        if problem > 0 {
+            operations.push(' ');
+        }
+        operations.push_str(&format!("{:<width$}", random.pick(&['*', '+'])));
     }
+    lines.iter().map(|line| format!("{}\n", line)).collect()
 }
@@ -1,3 +1,13 @@
+use crate::answer::Answer;
+use crate::simulation::Simulation;
+use crate::solution::Solution;
+use crate::utils::parse::{ParseError, lines};
+use crate::utils::point::{Map, Point2D};
+use crate::utils::random::Random;
+use crate::utils::trace::trace;
+use std::collections::HashMap;
+use std::ops::AddAssign;
+
 /**
 --- Day 7: Laboratories ---
 
@@ -235,94 +245,161 @@ Apply the many-worlds interpretation of quantum tachyon splitting to your
 manifold diagram. In total, how many different timelines would a single
 tachyon particle end up on?
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use std::collections::HashMap;
-    use std::ops::AddAssign;
-    use std::path::Path;
-    #[test]
-    fn input_example() {
-        let input = r#".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-..............."#;
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-
-        let split = count_timeline(lines.into_iter());
-
-        assert_eq!(split.0, 21);
-        assert_eq!(split.1, 40);
-    }
+pub struct Day07 {
+    map: Map,
+}
 
-    fn count_timeline<T>(lines: T) -> (usize, usize)
-    where
-        T: Iterator<Item = String>,
-    {
-        let mut split = 0;
-        let mut beams: HashMap<usize, usize> = HashMap::new();
-        for (n, line) in lines.enumerate() {
-            if n == 0 {
-                match line.find("S") {
-                    None => {
-                        println!("Cannot find starting position in input");
-                    }
-                    Some(i) => {
-                        beams.insert(i, 1);
-                    }
-                }
-            } else {
-                for (i, c) in line.chars().enumerate() {
-                    if c == '^' {
-                        match beams.remove(&i) {
-                            None => {}
-                            Some(value) => {
-                                split += 1;
-                                beams.entry(i + 1).or_insert(0).add_assign(value);
-                                beams.entry(i - 1).or_insert(0).add_assign(value);
-                            }
-                        }
-                    }
-                }
-                println!("Beams: {:?}", beams);
+impl Solution for Day07 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = lines(input).peekable();
+        match lines.peek() {
+            None => return Err(ParseError::missing(input, "expected the manifold diagram")),
+            Some(first) if !first.text.contains('S') => {
+                return Err(first.error(first.text, "expected the start position `S`"));
+            }
+            _ => {}
+        }
+        let mut rows: Vec<Vec<char>> = Vec::new();
+        for line in lines {
+            line.expect_chars(".^S")?;
+            let row: Vec<char> = line.text.chars().collect();
+            if let Some(first) = rows.first()
+                && first.len() != row.len()
+            {
+                let message = format!("expected {} cells, found {}", first.len(), row.len());
+                return Err(line.error(line.text, message));
             }
+            rows.push(row);
+        }
+        Ok(Day07 {
+            map: Map::new(rows),
+        })
+    }
+
+    fn part1(&self) -> Option<Answer> {
+        Some(count_timeline(self.map.clone()).0.into())
+    }
+
+    fn part2(&self) -> Option<Answer> {
+        Some(count_timeline(self.map.clone()).1.into())
+    }
+}
+
+/// Builds the beams going down the manifold, for the step debugger.
+pub(crate) fn simulation(input: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(Box::new(Beams::new(Day07::parse(input)?.map)))
+}
+
+/// The beams going down the manifold one row at a time, drawn as `|`.
+struct Beams {
+    map: Map,
+    row: i64,
+    /// The number of timelines of each beam, by column.
+    beams: HashMap<i64, usize>,
+    split: usize,
+}
+
+impl Beams {
+    fn new(map: Map) -> Self {
+        // The start position is checked when parsing.
+        let start = (0..map.x_max())
+            .find(|x| map.get(&Point2D::new(*x, 0)) == Some('S'))
+            .unwrap();
+        Beams {
+            map,
+            row: 0,
+            beams: HashMap::from([(start, 1)]),
+            split: 0,
         }
-        let timeline = beams.values().into_iter().sum();
-        (split, timeline)
     }
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day07.txt");
+    fn timelines(&self) -> usize {
+        self.beams.values().sum()
+    }
+}
 
-        let lines = read_lines(path);
+impl Simulation for Beams {
+    fn step(&mut self) -> bool {
+        if self.row + 1 >= self.map.y_max() {
+            return false;
+        }
 
-        let split = count_timeline(lines.into_iter());
+        self.row += 1;
+        for x in 0..self.map.x_max() {
+            if self.map.get(&Point2D::new(x, self.row)) == Some('^')
+                && let Some(value) = self.beams.remove(&x)
+            {
+                self.split += 1;
+                self.beams.entry(x + 1).or_insert(0).add_assign(value);
+                self.beams.entry(x - 1).or_insert(0).add_assign(value);
+            }
+        }
+        for x in self.beams.keys() {
+            let p = Point2D::new(*x, self.row);
+            if self.map.get(&p) == Some('.') {
+                self.map.set(&p, '|');
+            }
+        }
+        trace!("Beams: {:?}", self.beams);
+        true
+    }
 
-        assert_eq!(split.0, 1587);
+    fn map(&self) -> &Map {
+        &self.map
     }
 
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day07.txt");
+    fn frontier(&self) -> Vec<Point2D> {
+        self.beams
+            .keys()
+            .map(|x| Point2D::new(*x, self.row))
+            .filter(|p| self.map.get(p).is_some())
+            .collect()
+    }
 
-        let lines = read_lines(path);
+    fn status(&self) -> String {
+        format!(
+            "row {}, {} beams, {} splits, {} timelines",
+            self.row,
+            self.beams.len(),
+            self.split,
+            self.timelines()
+        )
+    }
+}
 
-        let split = count_timeline(lines.into_iter());
+fn count_timeline(map: Map) -> (usize, usize) {
+    let mut beams = Beams::new(map);
+    while beams.step() {}
+    (beams.split, beams.timelines())
+}
 
-        assert_eq!(split.1, 5748679033029);
+/// Levels of the largest generated manifold.
+const MAX_LEVELS: usize = 60;
+
+/// A manifold of `size` levels of splitters below the start, `MAX_LEVELS` at
+/// most, each level filling the triangle the beams may reach, without
+/// adjacent splitters.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    // Each level at most doubles the timelines, which must fit in a `usize`.
+    let size = size.min(MAX_LEVELS);
+    let width = 2 * size + 3;
+    let center = width / 2;
+    let empty = format!("{}\n", ".".repeat(width));
+    let mut input = format!("{}S{}\n", ".".repeat(center), ".".repeat(center));
+    for level in 0..size {
+        input.push_str(&empty);
+        let row: String = (0..width)
+            .map(|x| {
+                let offset = x.abs_diff(center);
+                let reachable = offset <= level && (level - offset).is_multiple_of(2);
+                match reachable && random.chance(3, 4) {
+                    true => '^',
+                    false => '.',
+                }
+            })
+            .collect();
+        input.push_str(&format!("{}\n", row));
     }
+    input.push_str(&empty);
+    input
 }
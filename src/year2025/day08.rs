@@ -1,3 +1,12 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{Line, ParseError, lines};
+use crate::utils::point::Point3D;
+use crate::utils::random::Random;
+use crate::utils::trace::{debug, info, trace};
+use bimap::BiMap;
+use disjoint::DisjointSet;
+
 /**
 --- Day 8: Playground ---
 
@@ -101,157 +110,123 @@ produces 25272.
 
 Continue connecting the closest unconnected pairs of junction boxes
 together until they're all in the same circuit. What do you get if you
-multiply together the X coordinates of the last two junction boxes you need Your puzzle answer was 131150.
+multiply together the X coordinates of the last two junction boxes you need
 to connect?
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use crate::utils::point::Point3D;
-    use bimap::BiMap;
-    use disjoint::DisjointSet;
-    use std::path::Path;
-
-    #[test]
-    fn input_example() {
-        let input = r#"162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689"#;
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-        let total = part_one(lines.into_iter(), 10);
-        assert_eq!(total, 40);
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-        let total = part_two(lines.into_iter());
-        assert_eq!(total.unwrap(), 25272);
-    }
+pub struct Day08 {
+    boxes: BiMap<usize, Point3D>,
+    connections: i32,
+}
 
-    fn part_one<T>(iter: T, limit: i32) -> usize
-    where
-        T: Iterator<Item = String>,
-    {
-        let boxes = read_input(iter);
-        let couples = compute_distances(&boxes);
-
-        let mut disjoint_set = DisjointSet::with_len(boxes.len());
-        let mut count = 0;
-        for (p1, p2, _d) in couples {
-            // println!("({:?}, {:?}, {}", p1, p2, d);
-            if disjoint_set.join(p1, p2) {
-                // println!("Joining {} and {}", p1, p2);
-            }
-            count += 1;
-            if count == limit {
-                break;
-            }
-        }
+impl Solution for Day08 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day08 {
+            boxes: read_input(lines(input))?,
+            connections: 1000,
+        })
+    }
 
-        let mut sizes: Vec<usize> = disjoint_set.sets().into_iter().map(|p| p.len()).collect();
-        sizes.sort_by(|a, b| b.cmp(a));
-        println!("{:?}", sizes);
+    fn part1(&self) -> Option<Answer> {
+        Some(part_one(&self.boxes, self.connections).into())
+    }
 
-        let mut total = 1;
-        for j in 0..3 {
-            total *= sizes[j];
-        }
-        total
+    fn part2(&self) -> Option<Answer> {
+        part_two(&self.boxes).map(Answer::from)
     }
+}
 
-    fn part_two<T>(iter: T) -> Option<i64>
-    where
-        T: Iterator<Item = String>,
-    {
-        let boxes = read_input(iter);
-        let couples = compute_distances(&boxes);
-
-        let mut disjoint_set = DisjointSet::with_len(boxes.len());
-
-        for (p1, p2, d) in couples {
-            println!("({:?}, {:?}, {}", p1, p2, d);
-            if disjoint_set.join(p1, p2) {
-                println!("Joining {} and {}", p1, p2);
-                if disjoint_set.sets().len() == 1 {
-                    let box1 = boxes.get_by_left(&p1).unwrap();
-                    let box2 = boxes.get_by_left(&p2).unwrap();
-                    println!("Box1: {:?}, Box2: {:?}", box1, box2);
-                    return Option::from(box1.x() * box2.x());
-                }
-            }
-        }
+/// Parses the example, which only connects the ten closest pairs of its boxes.
+pub(crate) fn parse_example(input: &str) -> Result<Box<dyn Solution>, ParseError> {
+    let mut day = Day08::parse(input)?;
+    day.connections = 10;
+    Ok(Box::new(day))
+}
+
+fn part_one(boxes: &BiMap<usize, Point3D>, limit: i32) -> usize {
+    let couples = compute_distances(boxes);
 
-        None
+    let mut disjoint_set = DisjointSet::with_len(boxes.len());
+    let mut count = 0;
+    for (p1, p2, d) in couples {
+        trace!("({:?}, {:?}, {}", p1, p2, d);
+        if disjoint_set.join(p1, p2) {
+            trace!("Joining {} and {}", p1, p2);
+        }
+        count += 1;
+        if count == limit {
+            break;
+        }
     }
 
-    fn compute_distances(boxes: &BiMap<usize, Point3D>) -> Vec<(usize, usize, f64)> {
-        let mut couples: Vec<(usize, usize, f64)> = Vec::new();
+    let mut sizes: Vec<usize> = disjoint_set.sets().into_iter().map(|p| p.len()).collect();
+    sizes.sort_by(|a, b| b.cmp(a));
+    debug!("{:?}", sizes);
 
-        for (index1, box1) in boxes {
-            for (index2, box2) in boxes {
-                if index1 < index2 {
-                    let d = box1.distance(box2);
-                    couples.push((*index1, *index2, d));
-                }
-            }
-        }
+    sizes.iter().take(3).product()
+}
 
-        couples.sort_by(|a, b| f64::total_cmp(&a.2, &b.2));
-        couples
-    }
+fn part_two(boxes: &BiMap<usize, Point3D>) -> Option<i64> {
+    let couples = compute_distances(boxes);
+
+    let mut disjoint_set = DisjointSet::with_len(boxes.len());
 
-    fn read_input<T>(iter: T) -> BiMap<usize, Point3D>
-    where
-        T: Iterator<Item = String>,
-    {
-        let mut boxes: BiMap<usize, Point3D> = BiMap::new();
-        let mut count: usize = 0;
-        for line in iter {
-            let v: Vec<i64> = line
-                .split(",")
-                .into_iter()
-                .map(str::parse::<i64>)
-                .map(Result::unwrap)
-                .collect();
-            boxes.insert(count, Point3D::new(v[0], v[1], v[2]));
-            count += 1;
+    for (p1, p2, d) in couples {
+        trace!("({:?}, {:?}, {}", p1, p2, d);
+        if disjoint_set.join(p1, p2) {
+            trace!("Joining {} and {}", p1, p2);
+            if disjoint_set.sets().len() == 1 {
+                let box1 = boxes.get_by_left(&p1).unwrap();
+                let box2 = boxes.get_by_left(&p2).unwrap();
+                info!("Box1: {:?}, Box2: {:?}", box1, box2);
+                return Option::from(box1.x() * box2.x());
+            }
         }
-        boxes
     }
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day08.txt");
+    None
+}
 
-        let lines = read_lines(path);
+fn compute_distances(boxes: &BiMap<usize, Point3D>) -> Vec<(usize, usize, f64)> {
+    let mut couples: Vec<(usize, usize, f64)> = Vec::new();
 
-        let total = part_one(lines.into_iter(), 1000);
-        assert_eq!(total, 131150)
+    for (index1, box1) in boxes {
+        for (index2, box2) in boxes {
+            if index1 < index2 {
+                let d = box1.distance(box2);
+                couples.push((*index1, *index2, d));
+            }
+        }
     }
 
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day08.txt");
-        let lines = read_lines(path);
+    couples.sort_by(|a, b| f64::total_cmp(&a.2, &b.2));
+    couples
+}
 
-        let total = part_two(lines.into_iter());
+fn read_input<'a, T>(iter: T) -> Result<BiMap<usize, Point3D>, ParseError>
+where
+    T: Iterator<Item = Line<'a>>,
+{
+    let mut boxes: BiMap<usize, Point3D> = BiMap::new();
+    for (count, line) in iter.enumerate() {
+        let fields: Vec<&str> = line.text.split(",").collect();
+        let [x, y, z] = fields[..] else {
+            return Err(line.error(line.text, "expected `X,Y,Z`"));
+        };
+        let point = Point3D::new(line.parse(x)?, line.parse(y)?, line.parse(z)?);
+        boxes.insert(count, point);
+    }
+    Ok(boxes)
+}
 
-        assert_eq!(total.unwrap(), 2497445);
+/// `size` distinct junction boxes in a cube of 100000 a side.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let mut boxes = std::collections::HashSet::new();
+    let mut input = String::new();
+    while boxes.len() < size {
+        let point = [0; 3].map(|_| random.range(0..=99_999));
+        if boxes.insert(point) {
+            input.push_str(&format!("{},{},{}\n", point[0], point[1], point[2]));
+        }
     }
+    input
 }
@@ -1,3 +1,11 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{Line, ParseError, lines};
+use crate::utils::point::{Point2D, Rectangle};
+use crate::utils::random::Random;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
 /**
 --- Day 9: Movie Theater ---
 
@@ -174,111 +182,284 @@ tiles has area 24. One way to do this is between 9,5 and 2,3:
 Using two red tiles as opposite corners, what is the largest area of any
 rectangle you can make using only red and green tiles?
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use crate::utils::point::{Point2D, Rectangle};
-    use std::path::Path;
-
-    use itertools::Itertools;
-
-    #[test]
-    fn input_example() {
-        let input = r#"7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3"#;
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-
-        let a_max = max_area_part1(lines.into_iter());
-        assert_eq!(a_max, 50);
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-
-        let a_max = max_area_part2(lines.into_iter());
-        assert_eq!(a_max, 24);
+pub struct Day09 {
+    positions: Vec<Point2D>,
+}
+
+impl Solution for Day09 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let positions = read_input(lines(input))?;
+        if positions.is_empty() {
+            return Err(ParseError::missing(input, "expected red tiles"));
+        }
+        Ok(Day09 { positions })
+    }
+
+    fn part1(&self) -> Option<Answer> {
+        Some(max_area_part1(&self.positions).into())
     }
 
-    fn max_area_part1<T>(iter: T) -> i64
-    where
-        T: Iterator<Item = String>,
-    {
-        let position = read_input(iter);
+    fn part2(&self) -> Option<Answer> {
+        Some(max_area_part2(&self.positions).into())
+    }
+}
+
+fn max_area_part1(position: &[Point2D]) -> i64 {
+    let mut a_max = 0;
+    for x in position.iter().combinations(2) {
+        let rectangle = Rectangle::from(x[0], x[1]);
+        a_max = std::cmp::max(rectangle.area(), a_max);
+    }
+    a_max
+}
 
-        let mut a_max = 0;
-        for x in position.iter().combinations(2) {
-            let rectangle = Rectangle::from(x[0], x[1]);
-            a_max = std::cmp::max(rectangle.area(), a_max);
+fn max_area_part2(position: &[Point2D]) -> i64 {
+    let edges = find_egdes(position);
+
+    let mut a_max = 0;
+    for c in position.iter().combinations(2) {
+        let rectangle = Rectangle::from(c[0], c[1]);
+        // Without any edge going through it, the rectangle is either all
+        // inside the loop or all outside, as in the notch of an L.
+        if !edges.iter().any(|e| rectangle.overlaps_with(e))
+            && inside_twice(position, c[0].x() + c[1].x(), c[0].y() + c[1].y())
+        {
+            a_max = std::cmp::max(a_max, rectangle.area());
         }
-        a_max
     }
+    a_max
+}
 
-    fn max_area_part2<T>(iter: T) -> i64
-    where
-        T: Iterator<Item = String>,
-    {
-        let position = read_input(iter);
-        let edges = find_egdes(&position);
-
-        let mut a_max = 0;
-        for c in position.iter().combinations(2) {
-            let rectangle = Rectangle::from(c[0], c[1]);
-            if !edges.iter().any(|e| rectangle.overlaps_with(e)) {
-                a_max = std::cmp::max(a_max, rectangle.area());
-            }
+/// Whether a point is on or inside the loop of red tiles, its coordinates
+/// being doubled so that the center of any rectangle is a whole point.
+fn inside_twice(position: &[Point2D], x: i64, y: i64) -> bool {
+    let mut crossings = 0;
+    for (a, b) in position.iter().circular_tuple_windows() {
+        let (x_min, x_max) = (2 * a.x().min(b.x()), 2 * a.x().max(b.x()));
+        let (y_min, y_max) = (2 * a.y().min(b.y()), 2 * a.y().max(b.y()));
+        if (x_min..=x_max).contains(&x) && (y_min..=y_max).contains(&y) {
+            return true;
+        }
+        // Counts the vertical edges on the right, each end counted once.
+        if x_min == x_max && x_min > x && (y_min..y_max).contains(&y) {
+            crossings += 1;
         }
-        a_max
     }
+    crossings % 2 == 1
+}
+
+fn find_egdes(position: &[Point2D]) -> Vec<Rectangle> {
+    let mut edges: Vec<Rectangle> = Vec::new();
+
+    let len = position.len();
+    for i in 1..len {
+        edges.push(Rectangle::from(&position[i - 1], &position[i]));
+    }
+    edges.push(Rectangle::from(&position[0], &position[len - 1]));
+    edges
+}
+
+/// Checks every tile of every rectangle, to check the edge overlaps of
+/// `max_area_part2`.
+pub(crate) struct BruteForce {
+    positions: Vec<Point2D>,
+}
+
+impl Solution for BruteForce {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(BruteForce {
+            positions: Day09::parse(input)?.positions,
+        })
+    }
+
+    fn part1(&self) -> Option<Answer> {
+        None
+    }
+
+    fn part2(&self) -> Option<Answer> {
+        let tiles = red_and_green(&self.positions);
+        let area = self
+            .positions
+            .iter()
+            .tuple_combinations()
+            .filter(|(a, b)| {
+                let xs = a.x().min(b.x())..=a.x().max(b.x());
+                let ys = a.y().min(b.y())..=a.y().max(b.y());
+                itertools::iproduct!(xs, ys).all(|tile| tiles.contains(&tile))
+            })
+            .map(|(a, b)| Rectangle::from(a, b).area())
+            .max();
+        Some(area.unwrap_or(0).into())
+    }
+}
 
-    fn find_egdes(position: &Vec<Point2D>) -> Vec<Rectangle> {
-        let mut edges: Vec<Rectangle> = Vec::new();
+/// The tiles on the loop of red tiles or inside it: those that cannot be
+/// reached from outside of the bounding box without crossing the loop.
+fn red_and_green(positions: &[Point2D]) -> HashSet<(i64, i64)> {
+    let mut tiles = HashSet::new();
+    for (a, b) in positions.iter().circular_tuple_windows() {
+        for x in a.x().min(b.x())..=a.x().max(b.x()) {
+            for y in a.y().min(b.y())..=a.y().max(b.y()) {
+                tiles.insert((x, y));
+            }
+        }
+    }
 
-        let len = position.len();
-        for i in 1..len {
-            edges.push(Rectangle::from(&position[i - 1], &position[i]));
+    let xs = positions
+        .iter()
+        .map(Point2D::x)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let ys = positions
+        .iter()
+        .map(Point2D::y)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (x_min, x_max, y_min, y_max) = (xs.0 - 1, xs.1 + 1, ys.0 - 1, ys.1 + 1);
+    let mut outside = HashSet::from([(x_min, y_min)]);
+    let mut queue = vec![(x_min, y_min)];
+    while let Some((x, y)) = queue.pop() {
+        for next in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+            if (x_min..=x_max).contains(&next.0)
+                && (y_min..=y_max).contains(&next.1)
+                && !tiles.contains(&next)
+                && outside.insert(next)
+            {
+                queue.push(next);
+            }
         }
-        edges.push(Rectangle::from(&position[0], &position[len - 1]));
-        edges
     }
 
-    fn read_input<T>(iter: T) -> Vec<Point2D>
-    where
-        T: Iterator<Item = String>,
-    {
-        let mut boxes: Vec<Point2D> = Vec::new();
-        for line in iter {
-            let v: Vec<i64> = line
-                .split(",")
-                .into_iter()
-                .map(str::parse::<i64>)
-                .map(Result::unwrap)
-                .collect();
-            boxes.push(Point2D::new(v[0], v[1]));
+    itertools::iproduct!(x_min..=x_max, y_min..=y_max)
+        .filter(|tile| !outside.contains(tile))
+        .collect()
+}
+
+/// The outline of a random polyomino of `size` cells, its holes and
+/// cells touching only by a corner filled, and its corners spaced so that no
+/// two edges are adjacent.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let mut cells = vec![(0, 0)];
+    let mut filled = HashSet::from([(0, 0)]);
+    while cells.len() < size {
+        let (x, y) = *random.pick(&cells);
+        let (dx, dy) = *random.pick(&[(1, 0), (-1, 0), (0, 1), (0, -1)]);
+        if filled.insert((x + dx, y + dy)) {
+            cells.push((x + dx, y + dy));
         }
-        boxes
     }
+    while fill(&mut filled) {}
+    let outline = outline(&filled).expect("a filled polyomino has a simple outline");
+
+    let spacing = |random: &mut Random, values: Vec<i64>| {
+        let mut coordinate = random.range(0..=3);
+        let mut spaced = HashMap::new();
+        for value in values.into_iter().sorted().dedup() {
+            spaced.insert(value, coordinate);
+            coordinate += random.range(2..=4);
+        }
+        spaced
+    };
+    let xs = spacing(random, outline.iter().map(|(x, _)| *x).collect());
+    let ys = spacing(random, outline.iter().map(|(_, y)| *y).collect());
+    outline
+        .iter()
+        .map(|(x, y)| format!("{},{}\n", xs[x], ys[y]))
+        .collect()
+}
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day09.txt");
+/// Fills the holes of a polyomino and the corners between cells touching
+/// only by a corner, returning whether a cell was added.
+fn fill(cells: &mut HashSet<(i64, i64)>) -> bool {
+    let xs = cells
+        .iter()
+        .map(|(x, _)| *x)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let ys = cells
+        .iter()
+        .map(|(_, y)| *y)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (x_min, x_max, y_min, y_max) = (xs.0 - 1, xs.1 + 1, ys.0 - 1, ys.1 + 1);
+    let mut outside = HashSet::from([(x_min, y_min)]);
+    let mut queue = vec![(x_min, y_min)];
+    while let Some((x, y)) = queue.pop() {
+        for next in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+            if (x_min..=x_max).contains(&next.0)
+                && (y_min..=y_max).contains(&next.1)
+                && !cells.contains(&next)
+                && outside.insert(next)
+            {
+                queue.push(next);
+            }
+        }
+    }
 
-        let lines = read_lines(path);
-        let a_max = max_area_part1(lines.into_iter());
+    let mut added = false;
+    for (x, y) in itertools::iproduct!(x_min..x_max, y_min..y_max) {
+        if !outside.contains(&(x, y)) {
+            added |= cells.insert((x, y));
+        }
+        let block = [(x, y), (x + 1, y + 1), (x + 1, y), (x, y + 1)].map(|c| cells.contains(&c));
+        if block == [true, true, false, false] || block == [false, false, true, true] {
+            cells.insert(if block[0] { (x + 1, y) } else { (x, y) });
+            added = true;
+        }
+    }
+    added
+}
 
-        assert_eq!(a_max, 4776100539);
+/// The corners of the boundary of a set of unit cells, in order, or `None`
+/// when the boundary is not a single simple loop.
+fn outline(cells: &HashSet<(i64, i64)>) -> Option<Vec<(i64, i64)>> {
+    let mut next = HashMap::new();
+    for &(x, y) in cells {
+        let sides = [
+            ((x, y - 1), (x, y), (x + 1, y)),
+            ((x + 1, y), (x + 1, y), (x + 1, y + 1)),
+            ((x, y + 1), (x + 1, y + 1), (x, y + 1)),
+            ((x - 1, y), (x, y + 1), (x, y)),
+        ];
+        for (neighbor, from, to) in sides {
+            if !cells.contains(&neighbor) && next.insert(from, to).is_some() {
+                return None;
+            }
+        }
     }
 
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day09.txt");
-        let lines = read_lines(path);
+    let start = *next.keys().min().unwrap();
+    let mut path = vec![start];
+    let mut current = next[&start];
+    while current != start {
+        path.push(current);
+        current = next[&current];
+    }
+    if path.len() != next.len() {
+        return None;
+    }
+    // Only the corners are red tiles, not the points along a side.
+    let corners = path
+        .iter()
+        .circular_tuple_windows()
+        .filter(|(a, b, c)| (a.0 == b.0) != (b.0 == c.0))
+        .map(|(_, b, _)| *b)
+        .collect();
+    Some(corners)
+}
 
-        let a_max = max_area_part2(lines.into_iter());
-        assert_eq!(a_max, 1476550548);
+fn read_input<'a, T>(iter: T) -> Result<Vec<Point2D>, ParseError>
+where
+    T: Iterator<Item = Line<'a>>,
+{
+    let mut boxes: Vec<Point2D> = Vec::new();
+    for line in iter {
+        let (x, y) = line.split_once(line.text, ",")?;
+        boxes.push(Point2D::new(line.parse(x)?, line.parse(y)?));
     }
+    Ok(boxes)
 }
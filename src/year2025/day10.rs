@@ -1,3 +1,11 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{Line, ParseError, lines};
+use crate::utils::random::Random;
+use crate::utils::trace::{debug, trace};
+use itertools::Itertools;
+use microlp::{LinearExpr, OptimizationDirection, Problem};
+
 /**
 --- Day 10: Factory ---
 
@@ -145,161 +153,203 @@ Analyze each machine's joltage requirements and button wiring schematics.
 What is the fewest button presses required to correctly configure the
 joltage level counters on all of the machines?
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use itertools::Itertools;
-    use std::path::Path;
-
-    #[test]
-    fn input_example() {
-        let input = r#"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}"#;
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-        let sum = part_one(lines.into_iter());
-        assert_eq!(sum, 7);
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-        let sum = part_two(lines.into_iter());
-        assert_eq!(sum, 33);
+pub struct Day10 {
+    machines: Vec<Machine>,
+}
+
+/// A machine description: the light diagram, the buttons and the joltage requirements.
+struct Machine {
+    mask: u64,
+    button_masks: Vec<u64>,
+    jolts: Vec<i32>,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day10 {
+            machines: lines(input).map(read_line).collect::<Result<_, _>>()?,
+        })
     }
 
-    fn part_one<T>(lines: T) -> usize
-    where
-        T: Iterator<Item = String>,
-    {
-        lines.map(|line| solve_part_one(line)).sum::<usize>()
+    fn part1(&self) -> Option<Answer> {
+        part_one(&self.machines).map(Answer::from)
     }
 
-    fn solve_part_one(line: String) -> usize {
-        let (mask, button_masks, _) = read_line(line);
-        println!("button_masks = {:?}", button_masks);
+    fn part2(&self) -> Option<Answer> {
+        part_two(&self.machines).map(Answer::from)
+    }
+}
 
-        let all_mask_count = all_mask(button_masks.len());
-        println!("all_mask = {}", all_mask_count);
+/// The fewest presses over all machines, `None` if a machine cannot be
+/// configured.
+fn part_one(machines: &[Machine]) -> Option<usize> {
+    machines.iter().map(solve_part_one).sum()
+}
 
-        let mut min_button = usize::MAX;
-        for vec in button_masks.into_iter().powerset() {
-            let on = vec.iter().fold(0, |acc, &n| acc ^ n);
-            if mask == on {
-                println!("m {:?} -> lights = {:?}", vec, on);
-                min_button = std::cmp::min(min_button, vec.len());
-            }
-        }
-        println!("min_button = {}", min_button);
-        min_button
-    }
+fn solve_part_one(machine: &Machine) -> Option<usize> {
+    let button_masks = &machine.button_masks;
+    trace!("button_masks = {:?}", button_masks);
 
-    fn part_two<T>(lines: T) -> usize
-    where
-        T: Iterator<Item = String>,
-    {
-        lines.map(|line| solve_part_two(line)).sum::<usize>()
-    }
+    let all_mask_count = all_mask(button_masks.len());
+    trace!("all_mask = {}", all_mask_count);
 
-    fn solve_part_two(line: String) -> usize {
-        let (_, button_masks, jolts) = read_line(line);
-
-        let mut problem = Problem::new(OptimizationDirection::Minimize);
-        let max = jolts.iter().max().unwrap().clone();
-
-        let variables = (0..button_masks.len())
-            .map(|_| problem.add_integer_var(1.0, (0, max)))
-            .collect::<Vec<_>>();
-
-        for (i, &n) in jolts.iter().enumerate() {
-            problem.add_constraint(
-                button_masks
-                    .iter()
-                    .zip(&variables)
-                    .filter(|&(mask, _)| mask & (1 << i) != 0)
-                    .fold(LinearExpr::empty(), |mut ex, (_, &var)| {
-                        ex.add(var, 1.0);
-                        ex
-                    }),
-                microlp::ComparisonOp::Eq,
-                n as f64,
-            );
+    let mut min_button = usize::MAX;
+    for vec in button_masks.iter().powerset() {
+        let on = vec.iter().fold(0, |acc, &n| acc ^ n);
+        if machine.mask == on {
+            trace!("m {:?} -> lights = {:?}", vec, on);
+            min_button = std::cmp::min(min_button, vec.len());
         }
-        let solve = problem.solve().unwrap().objective().round() as usize;
-        solve
     }
+    debug!("min_button = {}", min_button);
+    (min_button != usize::MAX).then_some(min_button)
+}
 
-    fn read_line(line: String) -> (u64, Vec<u64>, Vec<i32>) {
-        let split: Vec<String> = line.split_whitespace().map(str::to_string).collect();
-        let len = split.len();
-        println!("{:?}", split);
-        let first = split.first().unwrap();
-        let mask = read_mask(&first);
-        println!("mask = {}", mask);
-        let button_masks: Vec<u64> = (1..len - 1)
-            .map(|i| split[i].clone())
-            .map(|s| read_button_mask(&s))
-            .collect();
-        let last = split.last().unwrap();
-        let jolts = read_jolts(&last);
-        (mask, button_masks, jolts)
+fn part_two(machines: &[Machine]) -> Option<usize> {
+    machines.iter().map(solve_part_two).sum()
+}
+
+fn solve_part_two(machine: &Machine) -> Option<usize> {
+    let button_masks = &machine.button_masks;
+    let jolts = &machine.jolts;
+
+    let mut problem = Problem::new(OptimizationDirection::Minimize);
+    let max = *jolts.iter().max()?;
+
+    let variables = (0..button_masks.len())
+        .map(|_| problem.add_integer_var(1.0, (0, max)))
+        .collect::<Vec<_>>();
+
+    for (i, &n) in jolts.iter().enumerate() {
+        problem.add_constraint(
+            button_masks
+                .iter()
+                .zip(&variables)
+                .filter(|&(mask, _)| mask & (1 << i) != 0)
+                .fold(LinearExpr::empty(), |mut ex, (_, &var)| {
+                    ex.add(var, 1.0);
+                    ex
+                }),
+            microlp::ComparisonOp::Eq,
+            n as f64,
+        );
     }
+    let solution = problem.solve().ok()?;
+    Some(solution.objective().round() as usize)
+}
 
-    fn all_mask(len: usize) -> u64 {
-        let mut all_mask: u64 = 0;
-        for i in 0..len {
-            all_mask |= 1 << i;
-        }
-        all_mask
+fn read_line(line: Line) -> Result<Machine, ParseError> {
+    let split: Vec<&str> = line.text.split_whitespace().collect();
+    trace!("{:?}", split);
+    let [first, buttons @ .., last] = split.as_slice() else {
+        return Err(line.error(line.text, "expected `[lights] (buttons)... {joltages}`"));
+    };
+    let mask = read_mask(line, first)?;
+    trace!("mask = {}", mask);
+    let button_masks: Vec<u64> = buttons
+        .iter()
+        .map(|button| read_button_mask(line, button))
+        .collect::<Result<_, _>>()?;
+    let jolts = read_jolts(line, last)?;
+    // Each light has a joltage, both being indexed by the same bits.
+    let lights = first.len() - 2;
+    if jolts.len() != lights {
+        let message = format!("expected {} joltages, found {}", lights, jolts.len());
+        return Err(line.error(last, message));
     }
+    Ok(Machine {
+        mask,
+        button_masks,
+        jolts,
+    })
+}
 
-    fn read_mask(s: &String) -> u64 {
-        let mut result = 0;
-        for (pos, char) in s.chars().enumerate() {
-            if char == '#' {
-                result |= 1 << (pos - 1) as u64;
-            }
-        }
-        result
+/// The inside of `s`, a slice of the line, which must be enclosed by `open` and `close`.
+fn enclosed<'a>(line: Line, s: &'a str, open: char, close: char) -> Result<&'a str, ParseError> {
+    s.strip_prefix(open)
+        .and_then(|s| s.strip_suffix(close))
+        .ok_or_else(|| line.error(s, format!("expected `{}...{}`", open, close)))
+}
+
+fn all_mask(len: usize) -> u64 {
+    let mut all_mask: u64 = 0;
+    for i in 0..len {
+        all_mask |= 1 << i;
     }
+    all_mask
+}
 
-    fn read_button_mask(s: &String) -> u64 {
-        let mut result = 0;
-        let x = &s[1..s.len() - 1];
-        for c in x.split(',') {
-            let n = c.parse::<u8>().unwrap();
-            result |= 1 << n as u64;
+fn read_mask(line: Line, s: &str) -> Result<u64, ParseError> {
+    let mut result = 0;
+    for (pos, char) in enclosed(line, s, '[', ']')?.char_indices() {
+        if pos >= 64 {
+            return Err(line.error(s, "expected at most 64 lights"));
+        }
+        match char {
+            '#' => result |= 1 << pos as u64,
+            '.' => {}
+            _ => {
+                let light = &s[pos + 1..pos + 1 + char.len_utf8()];
+                return Err(line.error(light, format!("expected `#` or `.`, found `{}`", char)));
+            }
         }
-        result
     }
+    Ok(result)
+}
 
-    fn read_jolts(s: &String) -> Vec<i32> {
-        let mut result = Vec::new();
-        for c in s[1..s.len() - 1].split(',') {
-            result.push(c.parse::<i32>().unwrap());
+fn read_button_mask(line: Line, s: &str) -> Result<u64, ParseError> {
+    let mut result = 0;
+    for c in enclosed(line, s, '(', ')')?.split(',') {
+        let n = line.parse::<u8>(c)?;
+        if n >= 64 {
+            return Err(line.error(c, "expected a light below 64"));
         }
-        result
+        result |= 1 << n as u64;
     }
+    Ok(result)
+}
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day10.txt");
-
-        let lines = read_lines(path);
-
-        let sum = part_one(lines.into_iter());
-
-        assert_eq!(sum, 486);
+fn read_jolts(line: Line, s: &str) -> Result<Vec<i32>, ParseError> {
+    let mut result = Vec::new();
+    for c in enclosed(line, s, '{', '}')?.split(',') {
+        result.push(line.parse::<i32>(c)?);
     }
+    Ok(result)
+}
 
-    use microlp::{LinearExpr, OptimizationDirection, Problem};
-
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day10.txt");
-
-        let lines = read_lines(path);
+/// `size` machines of three to ten lights and up to thirteen buttons, the
+/// lights and joltages being reached by random presses of the buttons.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let lights = random.range(3..=10) as usize;
+        let buttons: Vec<Vec<usize>> = (0..random.range(3..=13))
+            .map(|_| {
+                let mut wired: Vec<usize> = (0..lights).filter(|_| random.chance(2, 5)).collect();
+                if wired.is_empty() {
+                    wired.push(random.below(lights));
+                }
+                wired
+            })
+            .collect();
 
-        let result = part_two(lines.into_iter());
+        let mut on = vec![false; lights];
+        let mut jolts = vec![0; lights];
+        for button in &buttons {
+            let toggled = random.chance(1, 2);
+            let presses = random.range(0..=20);
+            for &light in button {
+                on[light] ^= toggled;
+                jolts[light] += presses;
+            }
+        }
 
-        assert_eq!(result, 17820);
+        let mask: String = on.iter().map(|on| if *on { '#' } else { '.' }).collect();
+        input.push_str(&format!("[{}]", mask));
+        for button in &buttons {
+            input.push_str(&format!(" ({})", button.iter().join(",")));
+        }
+        input.push_str(&format!(" {{{}}}\n", jolts.iter().join(",")));
     }
+    input
 }
@@ -1,3 +1,10 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{Line, ParseError, lines};
+use crate::utils::random::Random;
+use crate::utils::trace::debug;
+use std::collections::{HashMap, HashSet};
+
 /**
 --- Day 11: Reactor ---
 
@@ -103,143 +110,179 @@ However, only 2 paths from svr to out visit both dac and fft.
 Find all of the paths that lead from svr to out. How many of those paths
 visit both dac and fft?
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use std::collections::HashMap;
-    use std::path::Path;
-
-    #[test]
-    fn input_example() {
-        let input = r#"aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out"#;
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-
-        let result = part_one(lines.into_iter());
-        assert_eq!(result, 5);
-
-        let input = r#"svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out"#;
-
-        let lines = input.split("\n").into_iter().map(str::to_string);
-
-        let result = part_two(lines.into_iter());
-        assert_eq!(result, 2);
-    }
-
-    fn part_one<T>(lines: T) -> u64
-    where
-        T: Iterator<Item = String>,
-    {
-        let next_servers = read_input(lines);
-
-        let result = find_paths(&next_servers, &String::from("you"), &String::from("out"));
-        result
-    }
-
-    fn part_two<T>(lines: T) -> u64
-    where
-        T: Iterator<Item = String>,
-    {
-        let next_servers = read_input(lines);
-
-        let dac_to_fft = find_paths(&next_servers, "dac", "fft");
-        let fft_to_dac = find_paths(&next_servers, "fft", "dac");
-        if dac_to_fft > 0 {
-            find_paths(&next_servers, "svr", "dac")
-                * dac_to_fft
-                * find_paths(&next_servers, "fft", "out")
-        } else {
-            find_paths(&next_servers, "svr", "fft")
-                * fft_to_dac
-                * find_paths(&next_servers, "dac", "out")
-        }
+pub struct Day11 {
+    next_servers: HashMap<String, Vec<String>>,
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day11 {
+            next_servers: read_input(lines(input))?,
+        })
     }
 
-    fn read_input<T>(lines: T) -> HashMap<String, Vec<String>>
-    where
-        T: Iterator<Item = String>,
-    {
-        let mut next_servers: HashMap<String, Vec<String>> = HashMap::new();
-        for line in lines {
-            let splitted: Vec<String> = line.split_whitespace().map(str::to_string).collect();
-            let server = splitted[0][..splitted[0].len() - 1].to_string();
-            let outputs: Vec<String> = splitted[1..splitted.len()]
-                .into_iter()
-                .map(String::clone)
-                .collect();
-            next_servers.insert(server, outputs);
-        }
+    fn part1(&self) -> Option<Answer> {
+        Some(part_one(&self.next_servers).into())
+    }
+
+    fn part2(&self) -> Option<Answer> {
+        Some(part_two(&self.next_servers).into())
+    }
+}
+
+fn part_one(next_servers: &HashMap<String, Vec<String>>) -> u64 {
+    find_paths(next_servers, "you", "out")
+}
+
+fn part_two(next_servers: &HashMap<String, Vec<String>>) -> u64 {
+    let dac_to_fft = find_paths(next_servers, "dac", "fft");
+    let fft_to_dac = find_paths(next_servers, "fft", "dac");
+    if dac_to_fft > 0 {
+        find_paths(next_servers, "svr", "dac") * dac_to_fft * find_paths(next_servers, "fft", "out")
+    } else {
+        find_paths(next_servers, "svr", "fft") * fft_to_dac * find_paths(next_servers, "dac", "out")
+    }
+}
+
+/// Follows every path one by one, to check the product of `part_two`.
+pub(crate) struct BruteForce {
+    next_servers: HashMap<String, Vec<String>>,
+}
+
+impl Solution for BruteForce {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(BruteForce {
+            next_servers: read_input(lines(input))?,
+        })
+    }
+
+    fn part1(&self) -> Option<Answer> {
+        None
+    }
 
-        println!("{:?}", next_servers);
-        next_servers
+    fn part2(&self) -> Option<Answer> {
+        Some(self.paths("svr", false, false).into())
     }
+}
 
-    fn find_paths(graph: &HashMap<String, Vec<String>>, start: &str, end: &str) -> u64 {
-        let mut cache: HashMap<String, u64> = HashMap::new();
-        cache.insert(end.to_string(), 1);
-        visit(&mut cache, graph, &start.to_string())
+impl BruteForce {
+    /// The paths from a server to `out` through both `dac` and `fft`,
+    /// `dac` and `fft` telling whether they were visited already.
+    fn paths(&self, server: &str, dac: bool, fft: bool) -> u64 {
+        if server == "out" {
+            return (dac && fft) as u64;
+        }
+        self.next_servers.get(server).map_or(0, |outputs| {
+            outputs
+                .iter()
+                .map(|next| self.paths(next, dac || next == "dac", fft || next == "fft"))
+                .sum()
+        })
     }
+}
 
-    fn visit(
-        cache: &mut HashMap<String, u64>,
-        graph: &HashMap<String, Vec<String>>,
-        server_name: &String,
-    ) -> u64 {
-        match cache.get(server_name) {
-            None => {}
-            Some(value) => {
-                return *value;
+/// A random acyclic network from `svr` to `out` through `dac`, `fft` and
+/// `you`, with `size` other devices.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let letters = (3..).find(|n| 26usize.pow(*n) > 4 * size).unwrap();
+    let mut names: HashSet<String> = ["svr", "dac", "fft", "out", "you"].map(String::from).into();
+    let mut devices: Vec<String> = ["dac", "fft", "you"].map(String::from).into();
+    while devices.len() < size + 3 {
+        let name: String = (0..letters)
+            .map(|_| (b'a' + random.below(26) as u8) as char)
+            .collect();
+        if names.insert(name.clone()) {
+            devices.push(name);
+        }
+    }
+    random.shuffle(&mut devices);
+    devices.insert(0, String::from("svr"));
+    devices.push(String::from("out"));
+
+    // Outputs only go further in the list, so there is no cycle.
+    let mut outputs: Vec<Vec<usize>> = vec![Vec::new(); devices.len() - 1];
+    for (i, outputs) in outputs.iter_mut().enumerate() {
+        let later = devices.len() - i - 1;
+        let count = 1 + random.below(later.min(3));
+        while outputs.len() < count {
+            let output = i + 1 + random.below(later);
+            if !outputs.contains(&output) {
+                outputs.push(output);
             }
         }
-        if server_name == "out" {
-            return 0;
+    }
+    // Every device being an output of an earlier one, all are reachable from
+    // `svr`, and some paths go through both `dac` and `fft`.
+    let dac = devices.iter().position(|device| device == "dac").unwrap();
+    let fft = devices.iter().position(|device| device == "fft").unwrap();
+    let (first, second) = (dac.min(fft), dac.max(fft));
+    if !outputs[first].contains(&second) {
+        outputs[first].push(second);
+    }
+    let mut reached = vec![false; devices.len()];
+    for device in 0..devices.len() {
+        if device > 0 && !reached[device] {
+            outputs[random.below(device)].push(device);
         }
-
-        let mut res = 0;
-        for next_server in graph.get(server_name).unwrap() {
-            res += visit(cache, graph, next_server);
+        if let Some(outputs) = outputs.get(device) {
+            outputs.iter().for_each(|&output| reached[output] = true);
         }
-
-        cache.insert(server_name.to_string(), res);
-        res
     }
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day11.txt");
-        let lines = read_lines(path);
+    let mut lines: Vec<String> = outputs
+        .iter()
+        .enumerate()
+        .map(|(i, outputs)| {
+            let names: Vec<&str> = outputs.iter().map(|o| devices[*o].as_str()).collect();
+            format!("{}: {}\n", devices[i], names.join(" "))
+        })
+        .collect();
+    random.shuffle(&mut lines);
+    lines.concat()
+}
 
-        let result = part_one(lines.into_iter());
-        assert_eq!(result, 701);
+fn read_input<'a, T>(lines: T) -> Result<HashMap<String, Vec<String>>, ParseError>
+where
+    T: Iterator<Item = Line<'a>>,
+{
+    let mut next_servers: HashMap<String, Vec<String>> = HashMap::new();
+    for line in lines {
+        let (server, outputs) = line.split_once(line.text, ":")?;
+        if server.trim().is_empty() {
+            return Err(line.error(server, "expected a device name"));
+        }
+        let outputs: Vec<String> = outputs.split_whitespace().map(str::to_string).collect();
+        next_servers.insert(server.trim().to_string(), outputs);
     }
 
-    #[test]
-    fn input_part_2() {
-        let path = Path::new("data/day11.txt");
-        let lines = read_lines(path);
+    debug!("{:?}", next_servers);
+    Ok(next_servers)
+}
+
+fn find_paths(graph: &HashMap<String, Vec<String>>, start: &str, end: &str) -> u64 {
+    let mut cache: HashMap<String, u64> = HashMap::new();
+    cache.insert(end.to_string(), 1);
+    visit(&mut cache, graph, start)
+}
 
-        let result = part_two(lines.into_iter());
-        assert_eq!(result, 390108778818526);
+fn visit(
+    cache: &mut HashMap<String, u64>,
+    graph: &HashMap<String, Vec<String>>,
+    server_name: &str,
+) -> u64 {
+    if let Some(value) = cache.get(server_name) {
+        return *value;
     }
+    if server_name == "out" {
+        return 0;
+    }
+
+    // A device without a line of its own leads nowhere.
+    let mut res = 0;
+    for next_server in graph.get(server_name).into_iter().flatten() {
+        res += visit(cache, graph, next_server);
+    }
+
+    cache.insert(server_name.to_string(), res);
+    res
 }
@@ -1,3 +1,9 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::utils::parse::{Line, ParseError, lines};
+use crate::utils::random::Random;
+use crate::utils::trace::{debug, trace};
+
 /**
 --- Day 12: Christmas Tree Farm ---
 
@@ -148,134 +154,153 @@ suddenly flashes brightly when a large star magically appears above it! As
 your eyes readjust, you think you notice a portly man with a white beard
 disappear into the crowd.
 */
-#[cfg(test)]
-mod tests {
-    use crate::utils::files::read_lines;
-    use std::path::Path;
-
-    #[test]
-    fn input_example() {
-        let input = r#"0:
-###
-##.
-##.
-
-1:
-###
-##.
-.##
-
-2:
-.##
-###
-##.
-
-3:
-##.
-###
-##.
-
-4:
-###
-#..
-###
-
-5:
-###
-.#.
-###
-
-4x4: 0 0 0 0 2 0
-12x5: 1 0 1 0 2 2
-12x5: 1 0 1 0 3 2"#;
-
-        let mut lines = input.split("\n").into_iter().map(str::to_string);
-
-        let solution = part_one_full(&mut lines);
-
-        assert_eq!(solution, 3); // Real solution is 2 !
+pub struct Day12 {
+    shapes: Vec<Shape>,
+    regions: Vec<Region>,
+}
+
+/// A region under a tree: its size and how many presents of each shape must fit.
+#[derive(Debug)]
+struct Region {
+    width: usize,
+    length: usize,
+    counts: Vec<usize>,
+}
+
+/// A present, as drawn in the input.
+type Shape = [[char; 3]; 3];
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (shapes, regions) = parse_input(input)?;
+        Ok(Day12 { shapes, regions })
     }
 
-    fn part_one_full<T>(mut lines: &mut T) -> i32
-    where
-        T: Iterator<Item = String>,
-    {
-        let (result, data) = parse_input(&mut lines);
-
-        let sizes: Vec<usize> = result.iter().map(|p| polyminos_size(&p)).collect();
-
-        let mut solution = 0;
-        for line in data {
-            println!("{}", line);
-            let (s1, s2) = line.split_once(": ").unwrap();
-            let (sl, sh) = s1.split_once('x').unwrap();
-            let l: usize = sl.parse().unwrap();
-            let h: usize = sh.parse().unwrap();
-            let available = l * h;
-
-            let objective = s2
-                .split(' ')
-                .map(|s| s.parse::<usize>())
-                .map(Result::unwrap)
-                .collect::<Vec<usize>>();
-            let needed = objective
-                .iter()
-                .enumerate()
-                .map(|(i, v)| sizes[i] * v)
-                .sum::<usize>();
-            println!("{} => {}", available, needed);
-            if needed <= available {
-                solution += 1;
-            }
-        }
-        solution
+    fn part1(&self) -> Option<Answer> {
+        Some(part_one_full(&self.shapes, &self.regions).into())
     }
 
-    fn parse_input<T>(lines: &mut T) -> (Vec<[[char; 3]; 3]>, Vec<String>)
-    where
-        T: Iterator<Item = String>,
-    {
-        let mut result: Vec<[[char; 3]; 3]> = Vec::new();
-        let mut data: Vec<String> = Vec::new();
-        while let Some(line) = lines.next() {
-            if line.trim().is_empty() {
-                continue;
-            }
+    /// Day 12 only has one puzzle: the second star is given for free.
+    fn part2(&self) -> Option<Answer> {
+        None
+    }
+}
 
-            if line.ends_with(':') {
-                let mut block = [[' '; 3]; 3];
+fn part_one_full(shapes: &[Shape], regions: &[Region]) -> i32 {
+    let sizes: Vec<usize> = shapes.iter().map(polyminos_size).collect();
+
+    let mut solution = 0;
+    for region in regions {
+        trace!("{:?}", region);
+        let available = region.width * region.length;
+
+        let needed = region
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(i, v)| sizes[i] * v)
+            .sum::<usize>();
+        trace!("{} => {}", available, needed);
+        if needed <= available {
+            solution += 1;
+        }
+    }
+    solution
+}
 
-                for row in 0..3 {
-                    if let Some(l) = lines.next() {
-                        let chars: Vec<char> = l.chars().collect();
-                        block[row] = [chars[0], chars[1], chars[2]];
-                    }
-                }
+fn parse_input(input: &str) -> Result<(Vec<Shape>, Vec<Region>), ParseError> {
+    let mut result: Vec<Shape> = Vec::new();
+    let mut data: Vec<Region> = Vec::new();
+    let mut lines = lines(input);
+    while let Some(line) = lines.next() {
+        if line.text.trim().is_empty() {
+            continue;
+        }
 
-                result.push(block);
-            } else {
-                data.push(line);
+        if line.text.ends_with(':') {
+            let mut block = [[' '; 3]; 3];
+
+            for row in block.iter_mut() {
+                let Some(l) = lines.next() else {
+                    return Err(ParseError::missing(input, "expected a row of the shape"));
+                };
+                l.expect_chars("#.")?;
+                let chars: Vec<char> = l.text.chars().collect();
+                let [a, b, c] = chars[..] else {
+                    return Err(l.error(l.text, "expected 3 cells"));
+                };
+                *row = [a, b, c];
             }
-        }
 
-        println!("{:?}", result);
-        println!("{:?}", data);
-        (result, data)
+            result.push(block);
+        } else {
+            data.push(read_region(line, result.len())?);
+        }
     }
 
-    fn polyminos_size(poly: &[[char; 3]; 3]) -> usize {
-        poly.iter()
-            .map(|line| line.iter().filter(|c| **c == '#').count())
-            .sum::<usize>()
+    debug!("{:?}", result);
+    debug!("{:?}", data);
+    Ok((result, data))
+}
+
+/// Reads a `WxL: counts...` region, with a count for each of the `shapes`.
+fn read_region(line: Line, shapes: usize) -> Result<Region, ParseError> {
+    let (size, counts) = line.split_once(line.text, ": ")?;
+    let (width, length) = line.split_once(size, "x")?;
+    let counts = counts
+        .split(' ')
+        .map(|count| line.parse::<usize>(count))
+        .collect::<Result<Vec<_>, _>>()?;
+    if counts.len() > shapes {
+        let message = format!("expected at most {} counts, one per shape", shapes);
+        return Err(line.error(line.text, message));
     }
+    Ok(Region {
+        width: line.parse(width)?,
+        length: line.parse(length)?,
+        counts,
+    })
+}
 
-    #[test]
-    fn input_part_1() {
-        let path = Path::new("data/day12.txt");
-        let lines = read_lines(path);
+fn polyminos_size(poly: &Shape) -> usize {
+    poly.iter()
+        .map(|line| line.iter().filter(|c| **c == '#').count())
+        .sum::<usize>()
+}
 
-        let solution = part_one_full(&mut lines.into_iter());
+/// Six shapes of five to seven cells, then `size` regions of up to 50 a side
+/// with about as many cells of presents as they have room for.
+pub(crate) fn generate(random: &mut Random, size: usize) -> String {
+    let mut input = String::new();
+    let mut cells = Vec::new();
+    for index in 0..6 {
+        let mut shape = ['#'; 9];
+        let holes = random.range(2..=4) as usize;
+        while shape.iter().filter(|c| **c == '.').count() < holes {
+            shape[random.below(9)] = '.';
+        }
+        cells.push(9 - holes);
+        input.push_str(&format!("{}:\n", index));
+        for row in shape.chunks(3) {
+            input.push_str(&format!("{}\n", row.iter().collect::<String>()));
+        }
+        input.push('\n');
+    }
 
-        assert_eq!(solution, 531);
+    for _ in 0..size {
+        let width = random.range(4..=50) as usize;
+        let length = random.range(4..=50) as usize;
+        // Up to 20% more or less cells than the region has.
+        let target = width * length * random.range(80..=120) as usize / 100;
+        let mut counts = [0; 6];
+        let mut filled = 0;
+        while filled + 7 <= target {
+            let shape = random.below(6);
+            counts[shape] += 1;
+            filled += cells[shape];
+        }
+        let counts: Vec<String> = counts.iter().map(usize::to_string).collect();
+        input.push_str(&format!("{}x{}: {}\n", width, length, counts.join(" ")));
     }
+    input
 }
@@ -1,12 +1,186 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
+use crate::solution::{Puzzle, Shape};
+use std::collections::BTreeMap;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+pub const YEAR: u16 = 2025;
+
+/// Registers the solution of a day along with the source holding its statement.
+macro_rules! puzzle {
+    ($module:ident::$solution:ident) => {
+        Puzzle::new::<$module::$solution>(include_str!(concat!(stringify!($module), ".rs")))
+    };
+}
+
+/// Maps each `(year, day)` of this year to its registered puzzle.
+pub fn registry() -> BTreeMap<(u16, u8), Puzzle> {
+    BTreeMap::from([
+        ((YEAR, 1), puzzle!(day01::Day01).generator(day01::generate)),
+        ((YEAR, 2), puzzle!(day02::Day02).generator(day02::generate)),
+        (
+            (YEAR, 3),
+            puzzle!(day03::Day03)
+                .reference::<day03::BruteForce>()
+                .generator(day03::generate),
+        ),
+        (
+            (YEAR, 4),
+            puzzle!(day04::Day04)
+                .shape(Shape::Grid)
+                .simulation(day04::simulation)
+                .generator(day04::generate),
+        ),
+        ((YEAR, 5), puzzle!(day05::Day05).generator(day05::generate)),
+        (
+            (YEAR, 6),
+            // The columns of the worksheet are aligned with spaces.
+            puzzle!(day06::Day06)
+                .shape(Shape::SpacedGrid)
+                .generator(day06::generate),
+        ),
+        (
+            (YEAR, 7),
+            puzzle!(day07::Day07)
+                .shape(Shape::Grid)
+                .simulation(day07::simulation)
+                .generator(day07::generate),
+        ),
+        (
+            (YEAR, 8),
+            puzzle!(day08::Day08)
+                .example_parser(day08::parse_example)
+                .generator(day08::generate),
+        ),
+        (
+            (YEAR, 9),
+            puzzle!(day09::Day09)
+                .reference::<day09::BruteForce>()
+                .generator(day09::generate),
+        ),
+        ((YEAR, 10), puzzle!(day10::Day10).generator(day10::generate)),
+        (
+            (YEAR, 11),
+            puzzle!(day11::Day11)
+                .reference::<day11::BruteForce>()
+                .generator(day11::generate),
+        ),
+        (
+            (YEAR, 12),
+            // Only comparing areas over-counts the example, whose answer is 2.
+            puzzle!(day12::Day12)
+                .example_answer(1, "3")
+                .generator(day12::generate),
+        ),
+    ])
+}
+
+#[cfg(test)]
+crate::solution::puzzle_tests!(crate::year2025::YEAR;
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::year2025::{YEAR, registry};
+
+    #[test]
+    fn registry_days() {
+        let registry = registry();
+
+        let days: Vec<u8> = registry.keys().map(|(_, day)| *day).collect();
+        assert_eq!(days, (1..=12).collect::<Vec<u8>>());
+        assert!(registry.keys().all(|(year, _)| *year == YEAR));
+
+        let puzzle = registry.get(&(YEAR, 1)).unwrap();
+        let solution = (puzzle.parse)(&puzzle.examples()[0].input).unwrap();
+        assert_eq!(solution.part1(), Some(Answer::from(3)));
+        assert_eq!(solution.part2(), Some(Answer::from(6)));
+    }
+
+    #[test]
+    fn non_square_grids() {
+        let solve = |input: &str| {
+            let solution = (registry()[&(YEAR, 4)].parse)(input).unwrap();
+            (solution.part1(), solution.part2())
+        };
+
+        // Only the corners of a full grid have fewer than four neighbours.
+        let wide = solve("@@@@@\n@@@@@\n");
+        assert_eq!(wide, (Some(Answer::from(4)), Some(Answer::from(10))));
+        let tall = solve("@@\n@@\n@@\n@@\n@@\n@@\n");
+        assert_eq!(tall, (Some(Answer::from(4)), Some(Answer::from(12))));
+    }
+
+    #[test]
+    fn unknown_devices() {
+        let solve = |input: &str| {
+            let solution = (registry()[&(YEAR, 11)].parse)(input).unwrap();
+            (solution.part1(), solution.part2())
+        };
+
+        assert_eq!(solve(""), (Some(Answer::from(0)), Some(Answer::from(0))));
+        let dangling = solve("you: aaa out\nsvr: dac\ndac: fft\nfft: bbb\n");
+        assert_eq!(dangling, (Some(Answer::from(1)), Some(Answer::from(0))));
+    }
+
+    #[test]
+    fn unsolvable_inputs() {
+        let solve = |day: u8, input: &str| {
+            let solution = (registry()[&(YEAR, day)].parse)(input).unwrap();
+            (solution.part1(), solution.part2())
+        };
+
+        // The operation of the second problem has no column of numbers.
+        assert_eq!(solve(6, "1 2 \n+  *\n").1, None);
+        assert_eq!(solve(10, "[#.] (1) {1,0}\n"), (None, None));
+    }
+
+    #[test]
+    fn malformed_inputs() {
+        let registry = registry();
+        let error = |day: u8, input: &str| (registry[&(YEAR, day)].parse)(input).err().unwrap();
+
+        let error1 = error(1, "L68\n\nR48\n");
+        assert_eq!((error1.line, error1.column), (2, 1));
+        let error4 = error(4, "");
+        assert_eq!(
+            (error4.line, error4.message.as_str()),
+            (1, "expected the grid of rolls")
+        );
+        let error6 = error(6, "+ *\n1 2\n");
+        assert_eq!((error6.line, error6.text.as_str()), (2, "1 2"));
+        let error8 = error(8, "162,817,812\n57,618\n");
+        assert_eq!((error8.line, error8.text.as_str()), (2, "57,618"));
+        let error10 = error(10, "[.##.] (3) (1,x) {3,5,4,7}\n");
+        assert_eq!((error10.column, error10.text.as_str()), (15, "x"));
+        let lights = format!("[{}] (0) {{{}}}\n", "#".repeat(65), vec!["1"; 65].join(","));
+        assert_eq!(error(10, &lights).message, "expected at most 64 lights");
+        let error10 = error(10, "[.#] (1) {1,2,3}\n");
+        assert_eq!(error10.message, "expected 2 joltages, found 3");
+        let error12 = error(12, "0:\n###\n##\n");
+        assert_eq!((error12.line, error12.text.as_str()), (3, "##"));
+    }
+}
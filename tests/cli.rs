@@ -0,0 +1,63 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn run_example() {
+    let output = Command::new(env!("CARGO_BIN_EXE_RustAOC"))
+        .args(["run", "--year", "2025", "--day", "1", "--example"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("2025 day 01 part 1: 3"));
+    assert!(stdout.contains("2025 day 01 part 2: 6"));
+}
+
+#[test]
+fn unknown_day() {
+    let output = Command::new(env!("CARGO_BIN_EXE_RustAOC"))
+        .args(["run", "--year", "2015", "--day", "1"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr, "error: no solution registered for 2015 day 01\n");
+}
+
+#[test]
+fn debug_example() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_RustAOC"))
+        .args(["debug", "--year", "2025", "--day", "7", "--example"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"2\nr\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("step 2: row 2, 2 beams, 1 splits, 2 timelines"));
+    assert!(stdout.contains("step 15: row 15, 9 beams, 21 splits, 40 timelines"));
+    assert!(stdout.ends_with("the simulation is over\n"));
+}
+
+#[test]
+fn debug_malformed_input() {
+    let path = std::env::temp_dir().join(format!("rustaoc-debug-{}.txt", std::process::id()));
+    std::fs::write(&path, "..S..\n.....\n..^\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_RustAOC"))
+        .args(["debug", "--year", "2025", "--day", "7", "--input"])
+        .arg(&path)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains(":3:1: expected 5 cells, found 3\n"));
+    assert!(stderr.ends_with("3 | ..^\n  | ^^^\n"));
+}
@@ -0,0 +1,67 @@
+use rust_aoc::utils::files;
+use rust_aoc::utils::parse::{ParseError, lines};
+use rust_aoc::utils::point::{Map, Point2D, Point3D, Rectangle};
+
+#[test]
+fn points() {
+    let p = Point2D::new(3, -4);
+    assert_eq!((p.x(), p.y()), (3, -4));
+    assert_eq!(p.manhattan(), 7);
+    assert_eq!(p.move_xy(-3, 4), Point2D::new(0, 0));
+    assert_eq!(p.neighbors().len(), 8);
+    assert!(Point2D::new(0, 0).valid(1, 1));
+    assert!(!p.valid(10, 10));
+
+    let q = Point3D::new(1, 2, 2);
+    assert_eq!(q.distance(&Point3D::new(0, 0, 0)), 3.0);
+}
+
+#[test]
+fn maps() {
+    let mut map = Map::new(vec!["..@".chars().collect(), "@..".chars().collect()]);
+    assert_eq!((map.x_max(), map.y_max()), (3, 2));
+    assert_eq!(map.get(&Point2D::new(2, 0)), Some('@'));
+    assert_eq!(map.get(&Point2D::new(3, 0)), None);
+
+    map.set(&Point2D::new(1, 1), 'x');
+    assert_eq!(map.get(&Point2D::new(1, 1)), Some('x'));
+
+    let empty = Map::new(vec![]);
+    assert_eq!((empty.x_max(), empty.y_max()), (0, 0));
+    assert_eq!(empty.get(&Point2D::new(0, 0)), None);
+}
+
+#[test]
+fn rectangles() {
+    let a = Rectangle::from(&Point2D::new(7, 1), &Point2D::new(11, 7));
+    let b = Rectangle::new(9, 12, 5, 9);
+    assert_eq!(a.area(), 35);
+    assert!(a.overlaps_with(&b));
+    assert!(!a.overlaps_with(&Rectangle::new(12, 14, 0, 3)));
+}
+
+#[test]
+fn parse_errors() {
+    let input = "12,5\n7;3\n";
+    let result: Result<Vec<(i64, i64)>, ParseError> = lines(input)
+        .map(|line| {
+            let (x, y) = line.split_once(line.text, ",")?;
+            Ok((line.parse(x)?, line.parse(y)?))
+        })
+        .collect();
+
+    let error = result.unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert!(error.diagnostic("points").starts_with("points:2:1: "));
+}
+
+#[test]
+fn files() {
+    let text = files::read_string("a\nb\n".as_bytes()).unwrap();
+    assert_eq!(text, "a\nb\n");
+
+    let lines: Vec<String> = files::lines("a\nb\n".as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(lines, ["a", "b"]);
+}
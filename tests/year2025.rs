@@ -0,0 +1,43 @@
+use rust_aoc::answer::Answer;
+use rust_aoc::solution::{Solution, registry};
+use rust_aoc::year2025::day01::Day01;
+use rust_aoc::year2025::{self, YEAR};
+
+#[test]
+fn solve_a_day() {
+    let day01 = Day01::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n").unwrap();
+
+    assert_eq!(day01.part1(), Some(Answer::from(3)));
+    assert_eq!(day01.solve(2), Some(Answer::from(6)));
+    assert!(Day01::parse("L68\nX30\n").is_err());
+}
+
+#[test]
+fn registered_examples() {
+    let registry = registry();
+    assert_eq!(year2025::registry().len(), 12);
+
+    for ((year, day), puzzle) in registry.range((YEAR, 1)..=(YEAR, 12)) {
+        assert_eq!(*year, YEAR);
+        for (part, example) in (1..=2).zip(puzzle.examples()) {
+            let Some(expected) = example.answer else {
+                continue;
+            };
+            let solution = (puzzle.parse_example)(&example.input).unwrap();
+            assert_eq!(
+                solution.solve(part),
+                Some(expected),
+                "day {} part {}",
+                day,
+                part
+            );
+        }
+    }
+}
+
+#[test]
+fn answers() {
+    let answer: Answer = "171741365473332".parse().unwrap();
+    assert_eq!(answer, Answer::from(171741365473332_u64));
+    assert_eq!(answer.to_string(), "171741365473332");
+}